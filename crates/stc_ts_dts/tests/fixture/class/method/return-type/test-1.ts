@@ -0,0 +1,21 @@
+type Point = { x: number; y: number };
+
+export function origin() {
+    const p: Point = { x: 0, y: 0 };
+    return p;
+}
+
+export class Shape {
+    center() {
+        const p: Point = { x: 0, y: 0 };
+        return p;
+    }
+
+    #secret() {
+        return 1;
+    }
+
+    name(): string {
+        return "shape";
+    }
+}
@@ -69,6 +69,10 @@ impl Env {
         self.rule
     }
 
+    pub fn set_rule(&mut self, rule: Rule) {
+        self.rule = rule;
+    }
+
     pub fn declare_global_var(&mut self, name: JsWord, ty: Type) {
         ty.assert_clone_cheap();
 
@@ -198,6 +202,17 @@ pub struct Rule {
     pub no_unused_locals: bool,
     pub no_unused_parameters: bool,
     pub use_define_property_for_class_fields: bool,
+    /// Use the types of `@returns` and `@param` JSDoc tags as the types of
+    /// functions and parameters without a type annotation.
+    pub use_jsdoc_types: bool,
+    /// Record types used for the `design:paramtypes` metadata of decorated
+    /// methods.
+    pub emit_decorator_metadata: bool,
+    /// Report overload signatures which cannot be selected because an earlier
+    /// overload signature accepts all arguments of them.
+    ///
+    /// Generic signatures and signatures with a rest parameter are skipped.
+    pub report_unreachable_overloads: bool,
 
     pub jsx: JsxMode,
 }
@@ -83,6 +83,10 @@ impl Error {
             DiagnosticId::Error(format!("TS{}", ErrorKind::normalize_error_code(self.code()))),
         );
 
+        if let Some((related, msg)) = self.related_info() {
+            err.span_note(related, msg);
+        }
+
         err.emit();
     }
 }
@@ -524,10 +528,24 @@ pub enum ErrorKind {
 
     /// TS2394
     IncompatibleFnOverload {
+        /// Span of the overload signature.
         span: Span,
+        /// Span of the implementation signature, which is reported as the
+        /// related information.
+        impl_span: Span,
         cause: Box<Error>,
     },
 
+    /// Not an error of TypeScript. Reported only if
+    /// `Rule::report_unreachable_overloads` is enabled.
+    UnreachableOverload {
+        /// Span of the overload signature which cannot be selected.
+        span: Span,
+        /// Span of the earlier overload signature which accepts all arguments
+        /// of it.
+        shadowed_by: Span,
+    },
+
     /// TS2371
     InitializerDisallowedInAmbientContext {
         span: Span,
@@ -755,6 +773,9 @@ pub enum ErrorKind {
     WrongArgType {
         /// Span of argument.
         span: Span,
+        /// Span of the declared parameter, which is reported as the related
+        /// information.
+        param_span: Span,
         inner: Box<Error>,
     },
 
@@ -931,6 +952,11 @@ pub enum ErrorKind {
         span: Span,
     },
 
+    /// TS2815
+    ArgumentsInPropertyInitializer {
+        span: Span,
+    },
+
     NoSuchVar {
         span: Span,
         name: Id,
@@ -1181,6 +1207,10 @@ pub enum ErrorKind {
         span: Span,
     },
 
+    TS1014 {
+        span: Span,
+    },
+
     TS1016 {
         span: Span,
     },
@@ -1494,6 +1524,127 @@ pub enum ErrorKind {
     DuplicatePrivateStaticInstance {
         span: Span,
     },
+
+    /// TS2680
+    ThisParamMustBeFirst {
+        span: Span,
+    },
+
+    /// TS2730
+    ThisParamInArrowFn {
+        span: Span,
+    },
+
+    /// TS1225
+    TypePredicateParamNotFound {
+        span: Span,
+    },
+
+    /// TS2677
+    TypePredicateNotAssignableToParam {
+        span: Span,
+        cause: Box<Error>,
+    },
+
+    /// TS1228
+    TypePredicateNotAllowed {
+        span: Span,
+    },
+
+    /// TS2534
+    FnReturningNeverHasReachableEnd {
+        span: Span,
+    },
+
+    /// TS2383
+    ExportedAndNonExportedOverloadsMixed {
+        span: Span,
+    },
+
+    /// TS2384
+    AmbientAndNonAmbientOverloadsMixed {
+        span: Span,
+    },
+
+    /// TS1064
+    ReturnTypeOfAsyncFnNotPromise {
+        span: Span,
+    },
+
+    /// TS1163
+    YieldOutsideGenerator {
+        span: Span,
+    },
+
+    /// TS6133
+    UnusedTypeParam {
+        span: Span,
+    },
+
+    /// TS6205
+    AllTypeParamsUnused {
+        span: Span,
+    },
+
+    /// TS2373
+    ParamReferencesLaterParam {
+        span: Span,
+    },
+
+    /// TS4104
+    ReadOnlyArrayAssignedToMutable {
+        span: Span,
+    },
+
+    /// TS8024
+    JsDocParamNameNotFound {
+        span: Span,
+    },
+
+    /// TS2775
+    AssertionTargetWithoutTypeAnn {
+        span: Span,
+    },
+
+    /// TS2314
+    GenericTypeRequiresTypeArgs {
+        span: Span,
+        count: usize,
+    },
+
+    /// TS2707
+    GenericTypeRequiresTypeArgsInRange {
+        span: Span,
+        min: usize,
+        max: usize,
+    },
+
+    /// TS1433
+    ///
+    /// `function f(...this: Foo) {}`
+    ThisParamCannotBeRest {
+        span: Span,
+    },
+
+    /// TS1005
+    ///
+    /// `function f(this?: Foo) {}`
+    ///
+    /// tsc reports this while parsing, as `this` cannot be followed by `?`.
+    ThisParamCannotBeOptional {
+        span: Span,
+    },
+
+    /// TS2313
+    CircularTypeParamConstraint {
+        span: Span,
+        name: Id,
+    },
+
+    /// TS2589
+    InstantiationExcessivelyDeep {
+        span: Span,
+    },
 }
 
 #[cfg(target_pointer_width = "64")]
@@ -1656,6 +1807,7 @@ impl ErrorKind {
     /// TypeScript error code.
     pub fn code(&self) -> usize {
         match self {
+            ErrorKind::TS1014 { .. } => 1014,
             ErrorKind::TS1016 { .. } => 1016,
             ErrorKind::TS1063 { .. } => 1063,
             ErrorKind::TS1094 { .. } => 1094,
@@ -1864,6 +2016,8 @@ impl ErrorKind {
 
             ErrorKind::ArgumentsCannotBeUsedInAsyncFnInEs3OrEs5 { .. } => 2522,
 
+            ErrorKind::ArgumentsInPropertyInitializer { .. } => 2815,
+
             ErrorKind::NoMatchingOverload { .. } => 2769,
 
             ErrorKind::NoSuchVarForShorthand { .. } => 18004,
@@ -2062,6 +2216,50 @@ impl ErrorKind {
 
             ErrorKind::DuplicatePrivateStaticInstance { .. } => 2804,
 
+            ErrorKind::ThisParamMustBeFirst { .. } => 2680,
+
+            ErrorKind::ThisParamInArrowFn { .. } => 2730,
+
+            ErrorKind::TypePredicateParamNotFound { .. } => 1225,
+
+            ErrorKind::TypePredicateNotAssignableToParam { .. } => 2677,
+
+            ErrorKind::TypePredicateNotAllowed { .. } => 1228,
+
+            ErrorKind::FnReturningNeverHasReachableEnd { .. } => 2534,
+
+            ErrorKind::ExportedAndNonExportedOverloadsMixed { .. } => 2383,
+
+            ErrorKind::AmbientAndNonAmbientOverloadsMixed { .. } => 2384,
+
+            ErrorKind::ReturnTypeOfAsyncFnNotPromise { .. } => 1064,
+
+            ErrorKind::YieldOutsideGenerator { .. } => 1163,
+
+            ErrorKind::UnusedTypeParam { .. } => 6133,
+
+            ErrorKind::AllTypeParamsUnused { .. } => 6205,
+
+            ErrorKind::ParamReferencesLaterParam { .. } => 2373,
+
+            ErrorKind::ReadOnlyArrayAssignedToMutable { .. } => 4104,
+
+            ErrorKind::JsDocParamNameNotFound { .. } => 8024,
+
+            ErrorKind::AssertionTargetWithoutTypeAnn { .. } => 2775,
+
+            ErrorKind::GenericTypeRequiresTypeArgs { .. } => 2314,
+
+            ErrorKind::GenericTypeRequiresTypeArgsInRange { .. } => 2707,
+
+            ErrorKind::ThisParamCannotBeRest { .. } => 1433,
+
+            ErrorKind::ThisParamCannotBeOptional { .. } => 1005,
+
+            ErrorKind::CircularTypeParamConstraint { .. } => 2313,
+
+            ErrorKind::InstantiationExcessivelyDeep { .. } => 2589,
+
             _ => 0,
         }
     }
@@ -2091,6 +2289,21 @@ impl ErrorKind {
         self.code() == 2322
     }
 
+    /// Returns the span of the declaration related to this error and the
+    /// message describing it, if any.
+    pub fn related_info(&self) -> Option<(Span, &'static str)> {
+        match self {
+            Self::WrongArgType { param_span, .. } if !param_span.is_dummy() => Some((*param_span, "The parameter is declared here")),
+            Self::IncompatibleFnOverload { impl_span, .. } if !impl_span.is_dummy() => {
+                Some((*impl_span, "The implementation signature is declared here"))
+            }
+            Self::UnreachableOverload { shadowed_by, .. } if !shadowed_by.is_dummy() => {
+                Some((*shadowed_by, "The overload signature shadowing it is declared here"))
+            }
+            _ => None,
+        }
+    }
+
     pub fn is_type_not_found(&self) -> bool {
         matches!(self, Self::NoSuchType { .. } | Self::NoSuchTypeButVarExists { .. })
     }
@@ -2190,6 +2403,14 @@ impl Errors {
     pub fn append_errors(&mut self, other: &mut Self) {
         self.append(&mut other.0)
     }
+
+    /// Sorts errors by the start of their spans, and then by their codes.
+    ///
+    /// The sort is stable, so errors with the same position and code are kept
+    /// in the reported order.
+    pub fn sort(&mut self) {
+        self.0.sort_by_key(|err| (err.span().lo, err.code()));
+    }
 }
 
 impl Extend<Error> for Errors {
@@ -276,7 +276,10 @@ impl Analyzer<'_, '_> {
                                     rm.type_params.as_ref(),
                                     &rm.params,
                                     Some(&rm.ret_ty),
-                                    opts,
+                                    AssignOpts {
+                                        is_params_of_method_definition: true,
+                                        ..opts
+                                    },
                                 )
                                 .context("tried to assign a class method to another one")?;
 
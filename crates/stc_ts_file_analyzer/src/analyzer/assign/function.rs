@@ -3,6 +3,7 @@ use std::borrow::Cow;
 use fxhash::FxHashMap;
 use itertools::Itertools;
 use stc_ts_ast_rnode::{RBindingIdent, RIdent, RPat, RTsLit};
+use stc_ts_env::Rule;
 use stc_ts_errors::{
     debug::{dump_type_map, force_dump_type_as_string},
     DebugExt, ErrorKind,
@@ -27,6 +28,32 @@ use crate::{
 
 /// Methods to handle assignment to function types and constructor types.
 impl Analyzer<'_, '_> {
+    /// Returns `true` if `source` is assignable to `target` under `rule`.
+    ///
+    /// Parameters are compared contravariantly if
+    /// [Rule::strict_function_types] is enabled and bivariantly
+    /// otherwise, while return types are compared covariantly.
+    ///
+    /// `rule` is used instead of the rule of the analyzer only for this
+    /// comparison. Types referenced by the signatures are resolved using the
+    /// scope of the analyzer, which is why this is not a method of
+    /// [Function].
+    pub fn is_fn_compatible_with(&mut self, source: &Function, target: &Function, rule: Rule) -> bool {
+        let orig_rule = self.env.rule();
+        self.env.set_rule(rule);
+
+        let res = self.assign(
+            target.span,
+            &mut Default::default(),
+            &Type::Function(target.clone()).freezed(),
+            &Type::Function(source.clone()).freezed(),
+        );
+
+        self.env.set_rule(orig_rule);
+
+        res.is_ok()
+    }
+
     pub(crate) fn assign_to_fn_like(
         &mut self,
         data: &mut AssignData,
@@ -348,11 +375,10 @@ impl Analyzer<'_, '_> {
         };
 
         // TypeScript functions are bivariant if strict_function_types is false.
-        if !self.env.rule().strict_function_types || opts.is_params_of_method_definition {
-            if self.assign_params(data, r_params, l_params, opts).is_ok() {
-                return Ok(());
-            }
-        }
+        //
+        // Bivariance only affects parameters, so return types are still compared.
+        let params_are_bivariant = (!self.env.rule().strict_function_types || opts.is_params_of_method_definition)
+            && self.assign_params(data, r_params, l_params, opts).is_ok();
 
         // () => void
         //
@@ -361,7 +387,7 @@ impl Analyzer<'_, '_> {
         // (t: unknown, t1: unknown) => void
         //
         // So we check for length first.
-        if !r_params.is_empty() {
+        if !params_are_bivariant && !r_params.is_empty() {
             self.assign_params(
                 data,
                 l_params,
@@ -378,17 +404,31 @@ impl Analyzer<'_, '_> {
             if let Some(r_ret_ty) = r_ret_ty {
                 // TODO(kdy1): Verify type parameters.
 
+                let for_overload = opts.for_overload;
+
+                // An overload returning `void` is compatible with any implementation.
+                if for_overload && r_ret_ty.is_kwd(TsKeywordTypeKind::TsVoidKeyword) {
+                    return Ok(());
+                }
+
                 let opts = AssignOpts {
                     // We are done with the overload context.
                     for_overload: false,
                     allow_assignment_of_void: Some(opts.allow_assignment_of_void.unwrap_or(true)),
-                    allow_assignment_to_void: !opts.for_overload,
+                    allow_assignment_to_void: !for_overload,
 
                     ..opts
                 };
 
-                self.assign_inner(data, l_ret_ty, r_ret_ty, opts)
-                    .context("tried to assign the return type of a function to the return type of another function")?;
+                let res = self.assign_inner(data, l_ret_ty, r_ret_ty, opts);
+
+                // The return type of an overload can be a subtype or a supertype of the return
+                // type of the implementation.
+                if res.is_err() && for_overload && self.assign_inner(data, r_ret_ty, l_ret_ty, opts).is_ok() {
+                    return Ok(());
+                }
+
+                res.context("tried to assign the return type of a function to the return type of another function")?;
             }
         }
 
@@ -434,7 +474,10 @@ impl Analyzer<'_, '_> {
                     r_type_params.as_ref(),
                     r_params,
                     Some(r_ret_ty),
-                    opts,
+                    AssignOpts {
+                        is_params_of_method_definition: opts.is_params_of_method_definition || l.metadata.is_method(),
+                        ..opts
+                    },
                 )
                 .context("tried to assign a function to another one")?;
 
@@ -813,6 +856,39 @@ impl Analyzer<'_, '_> {
         Ok(())
     }
 
+    /// Compares explicit `this` parameters of functions.
+    ///
+    /// A function with `this: void` doesn't use `this`, so it can be assigned
+    /// to any function type.
+    fn assign_this_params(&mut self, data: &mut AssignData, l: &[FnParam], r: &[FnParam], opts: AssignOpts) -> VResult<()> {
+        fn this_param(params: &[FnParam]) -> Option<&Type> {
+            params.iter().find_map(|p| match p.pat {
+                RPat::Ident(RBindingIdent {
+                    id: RIdent { sym: js_word!("this"), .. },
+                    ..
+                }) => Some(&*p.ty),
+                _ => None,
+            })
+        }
+
+        let (l_this, r_this) = match (this_param(l), this_param(r)) {
+            (Some(l_this), Some(r_this)) => (l_this, r_this),
+            _ => return Ok(()),
+        };
+
+        if r_this.is_kwd(TsKeywordTypeKind::TsVoidKeyword) {
+            return Ok(());
+        }
+
+        // `this` parameters are compared bivariantly.
+        if self.assign_with_opts(data, r_this, l_this, opts).is_ok() {
+            return Ok(());
+        }
+
+        self.assign_with_opts(data, l_this, r_this, opts)
+            .context("tried to assign `this` parameters of functions")
+    }
+
     /// # Validation of parameter count
     ///
     /// A parameter named `this` is excluded.
@@ -838,6 +914,8 @@ impl Analyzer<'_, '_> {
 
         let span = opts.span;
 
+        self.assign_this_params(data, l, r, opts)?;
+
         let mut li = l.iter().filter(|p| {
             !matches!(
                 p.pat,
@@ -640,6 +640,11 @@ impl Analyzer<'_, '_> {
         let to = to.normalize();
         let rhs = rhs.normalize();
 
+        // `type V = void` is `void` too.
+        if opts.allow_assignment_to_void && to.is_kwd(TsKeywordTypeKind::TsVoidKeyword) {
+            return Ok(());
+        }
+
         macro_rules! fail {
             () => {{
                 return Err(ErrorKind::AssignFailed {
@@ -809,6 +814,16 @@ impl Analyzer<'_, '_> {
             (Type::Tuple(..) | Type::Array(..), Type::Function(..) | Type::Constructor(..)) => {
                 fail!()
             }
+            (
+                Type::Tuple(..) | Type::Array(..),
+                Type::Operator(Operator {
+                    op: TsTypeOperatorOp::ReadOnly,
+                    ty,
+                    ..
+                }),
+            ) if matches!(ty.normalize(), Type::Tuple(..) | Type::Array(..)) => {
+                return Err(ErrorKind::ReadOnlyArrayAssignedToMutable { span }.into());
+            }
             (Type::TypeLit(TypeLit { members, .. }), Type::TypeLit(..)) => {
                 if members.is_empty() && !opts.for_overload {
                     return Ok(());
@@ -1410,9 +1425,19 @@ impl Analyzer<'_, '_> {
                 ty,
                 ..
             }) => {
+                // `readonly T[]` can be assigned to `readonly T[]`.
+                let rhs = match rhs {
+                    Type::Operator(Operator {
+                        op: TsTypeOperatorOp::ReadOnly,
+                        ty: rhs,
+                        ..
+                    }) => &**rhs,
+                    _ => rhs,
+                };
+
                 return self
                     .assign_with_opts(data, ty, rhs, opts)
-                    .context("tried to assign a type to an operand of readonly type")
+                    .context("tried to assign a type to an operand of readonly type");
             }
 
             _ => {}
@@ -0,0 +1,162 @@
+use std::borrow::Cow;
+
+use rnode::NodeId;
+use stc_ts_ast_rnode::{RBindingIdent, RIdent, RPat, RTsEntityName, RTsLit};
+use stc_ts_types::{FnParam, KeywordType, LitType, Type};
+use swc_atoms::js_word;
+use swc_common::Span;
+use swc_ecma_ast::TsKeywordTypeKind;
+
+use crate::analyzer::Analyzer;
+
+/// A type serialized for the `design:paramtypes` metadata, which is emitted
+/// for decorated members if `emitDecoratorMetadata` is enabled.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DesignType {
+    /// `void 0`
+    Void,
+    Number,
+    String,
+    Boolean,
+    BigInt,
+    Symbol,
+    Array,
+    Function,
+    Object,
+    /// A value with the name of the type, like a class.
+    Ref(RTsEntityName),
+}
+
+impl Analyzer<'_, '_> {
+    /// Returns the `design:paramtypes` metadata recorded for the function
+    /// `node_id`.
+    ///
+    /// Metadata is recorded only for decorated methods, or methods with a
+    /// decorated parameter, if [stc_ts_env::Rule::emit_decorator_metadata] is
+    /// enabled.
+    pub fn design_param_types(&self, node_id: NodeId) -> Option<&[DesignType]> {
+        self.data.design_param_types.get(&node_id).map(|v| &**v)
+    }
+
+    pub(super) fn record_design_param_types(&mut self, node_id: NodeId, params: &[FnParam]) {
+        let types = params
+            .iter()
+            .filter(|param| {
+                !matches!(
+                    param.pat,
+                    RPat::Ident(RBindingIdent {
+                        id: RIdent { sym: js_word!("this"), .. },
+                        ..
+                    })
+                )
+            })
+            .map(|param| match &param.pat {
+                // The element type is used for rest parameters.
+                RPat::Rest(..) => match param.ty.normalize() {
+                    Type::Array(arr) => self.design_type_of(param.span, &arr.elem_type),
+                    _ => DesignType::Object,
+                },
+                _ => self.design_type_of(param.span, &param.ty),
+            })
+            .collect();
+
+        self.data.design_param_types.insert(node_id, types);
+    }
+
+    fn design_type_of(&mut self, span: Span, ty: &Type) -> DesignType {
+        match ty.normalize() {
+            Type::Keyword(KeywordType { kind, .. }) => match kind {
+                TsKeywordTypeKind::TsNumberKeyword => DesignType::Number,
+                TsKeywordTypeKind::TsStringKeyword => DesignType::String,
+                TsKeywordTypeKind::TsBooleanKeyword => DesignType::Boolean,
+                TsKeywordTypeKind::TsBigIntKeyword => DesignType::BigInt,
+                TsKeywordTypeKind::TsSymbolKeyword => DesignType::Symbol,
+                TsKeywordTypeKind::TsVoidKeyword
+                | TsKeywordTypeKind::TsUndefinedKeyword
+                | TsKeywordTypeKind::TsNullKeyword
+                | TsKeywordTypeKind::TsNeverKeyword => DesignType::Void,
+                _ => DesignType::Object,
+            },
+
+            Type::Lit(LitType { lit, .. }) => match lit {
+                RTsLit::Number(..) => DesignType::Number,
+                RTsLit::Str(..) | RTsLit::Tpl(..) => DesignType::String,
+                RTsLit::Bool(..) => DesignType::Boolean,
+                RTsLit::BigInt(..) => DesignType::BigInt,
+            },
+
+            Type::Tpl(..) => DesignType::String,
+
+            Type::Array(..) | Type::Tuple(..) => DesignType::Array,
+
+            Type::Function(..) | Type::Constructor(..) => DesignType::Function,
+
+            Type::Enum(e) => design_type_of_enum(e.has_num, e.has_str),
+
+            Type::EnumVariant(v) => {
+                let kinds = self.find_type(&v.enum_name).ok().flatten().and_then(|mut types| {
+                    types.find_map(|ty| match ty.normalize() {
+                        Type::Enum(e) => Some((e.has_num, e.has_str)),
+                        _ => None,
+                    })
+                });
+
+                match kinds {
+                    Some((has_num, has_str)) => design_type_of_enum(has_num, has_str),
+                    None => DesignType::Object,
+                }
+            }
+
+            // `null` and `undefined` are ignored, and the union is serialized as
+            // `Object` if the remaining types are serialized differently.
+            Type::Union(u) => {
+                let mut found = None;
+
+                for ty in &u.types {
+                    if ty.is_null_or_undefined() || ty.is_never() {
+                        continue;
+                    }
+
+                    let ty = self.design_type_of(span, ty);
+                    match &found {
+                        Some(prev) if *prev != ty => return DesignType::Object,
+                        _ => found = Some(ty),
+                    }
+                }
+
+                found.unwrap_or(DesignType::Void)
+            }
+
+            Type::Ref(r) => {
+                let expanded = match self.normalize(Some(span), Cow::Borrowed(ty), Default::default()) {
+                    Ok(ty) => ty.into_owned(),
+                    Err(..) => return DesignType::Object,
+                };
+
+                match expanded.normalize() {
+                    Type::Class(..) | Type::ClassDef(..) => DesignType::Ref(r.type_name.clone()),
+
+                    // Built-in types like `Date` have a value with the same name.
+                    Type::Interface(..) => match &r.type_name {
+                        RTsEntityName::Ident(i) if self.env.get_global_var(span, &i.sym).is_ok() => DesignType::Ref(r.type_name.clone()),
+                        _ => DesignType::Object,
+                    },
+
+                    Type::Ref(..) => DesignType::Object,
+
+                    ty => self.design_type_of(span, ty),
+                }
+            }
+
+            _ => DesignType::Object,
+        }
+    }
+}
+
+fn design_type_of_enum(has_num: bool, has_str: bool) -> DesignType {
+    match (has_num, has_str) {
+        (true, false) => DesignType::Number,
+        (false, true) => DesignType::String,
+        _ => DesignType::Object,
+    }
+}
@@ -22,6 +22,7 @@ use swc_common::{iter::IdentifyLast, EqIgnoreSpan, Span, Spanned, SyntaxContext,
 use swc_ecma_ast::*;
 use swc_ecma_utils::private_ident;
 
+pub use self::decorator_metadata::DesignType;
 use self::type_param::StaticTypeParamValidator;
 use super::{expr::AccessPropertyOpts, pat::PatMode};
 use crate::{
@@ -39,6 +40,7 @@ use crate::{
     VResult,
 };
 
+mod decorator_metadata;
 mod order;
 mod type_param;
 
@@ -536,6 +538,10 @@ impl Analyzer<'_, '_> {
             },
         )?;
 
+        if c.kind != MethodKind::Setter && c.function.return_type.is_none() {
+            self.record_return_type_of_fn(c.span, c.function.node_id, &ret_ty);
+        }
+
         match c.kind {
             MethodKind::Method => Ok(ClassMember::Method(Method {
                 span: c.span,
@@ -691,6 +697,11 @@ impl Analyzer<'_, '_> {
 
                 let declared_ret_ty = try_opt!(c.function.return_type.validate_with(child));
                 let declared_ret_ty = declared_ret_ty.map(|ty| ty.freezed());
+                if c.kind == MethodKind::Getter {
+                    if let Some(Type::Predicate(p)) = declared_ret_ty.as_ref().map(|ty| ty.normalize()) {
+                        child.storage.report(ErrorKind::TypePredicateNotAllowed { span: p.span }.into())
+                    }
+                }
                 child.scope.declared_return_type = declared_ret_ty.clone();
 
                 let span = c.function.span;
@@ -713,6 +724,12 @@ impl Analyzer<'_, '_> {
             },
         )?;
 
+        if self.rule().emit_decorator_metadata
+            && (!c.function.decorators.is_empty() || c.function.params.iter().any(|p| !p.decorators.is_empty()))
+        {
+            self.record_design_param_types(c.function.node_id, &params);
+        }
+
         if c.kind == MethodKind::Getter && c.function.body.is_some() {
             // Inferred return type.
 
@@ -737,17 +754,13 @@ impl Analyzer<'_, '_> {
             })
         });
 
-        if c.kind != MethodKind::Setter {
-            let node_id = c.function.node_id;
-
+        if c.kind != MethodKind::Setter && c.function.return_type.is_none() {
             let ret_ty = if self.may_generalize(&ret_ty) {
                 ret_ty.clone().generalize_lit()
             } else {
                 *ret_ty.clone()
             };
-            if let Some(m) = &mut self.mutations {
-                m.for_fns.entry(node_id).or_default().ret_ty = Some(ret_ty);
-            }
+            self.record_return_type_of_fn(c_span, c.function.node_id, &ret_ty);
         }
 
         match c.kind {
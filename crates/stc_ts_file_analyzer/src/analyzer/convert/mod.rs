@@ -18,13 +18,13 @@ use stc_ts_types::{
     ConstructorSignature, FnParam, Id, IdCtx, ImportType, IndexSignature, IndexedAccessType, InferType, InferTypeMetadata, Interface,
     IntrinsicKind, Key, KeywordType, KeywordTypeMetadata, LitType, LitTypeMetadata, Mapped, MethodSignature, Operator, OptionalType,
     Predicate, PropertySignature, QueryExpr, QueryType, Ref, RefMetadata, RestType, StringMapping, Symbol, ThisType, TplElem, TplType,
-    TsExpr, Tuple, TupleElement, TupleMetadata, Type, TypeElement, TypeLit, TypeLitMetadata, TypeParam, TypeParamDecl,
-    TypeParamInstantiation,
+    TsExpr, Tuple, TupleElement, TupleMetadata, Type, TypeElement, TypeFlags, TypeLit, TypeLitMetadata, TypeParam, TypeParamDecl,
+    TypeParamInstantiation, TypeParamMetadata,
 };
 use stc_ts_utils::{find_ids_in_pat, PatExt};
 use stc_utils::{cache::Freeze, AHashSet};
 use swc_atoms::js_word;
-use swc_common::{Spanned, SyntaxContext, TypeEq, DUMMY_SP};
+use swc_common::{Span, Spanned, SyntaxContext, TypeEq, DUMMY_SP};
 use swc_ecma_ast::TsKeywordTypeKind;
 use tracing::warn;
 
@@ -91,7 +91,24 @@ impl Analyzer<'_, '_> {
                 );
             }
 
-            let params: Vec<TypeParam> = decl.params.validate_with(self)?;
+            let mut params: Vec<TypeParam> = decl.params.validate_with(self)?;
+
+            let circular = params
+                .iter()
+                .filter(|param| has_circular_constraint(&params, param))
+                .map(|param| param.name.clone())
+                .collect::<Vec<_>>();
+            for param in params.iter_mut().filter(|param| circular.contains(&param.name)) {
+                if let Some(constraint) = param.constraint.take() {
+                    self.storage.report(
+                        ErrorKind::CircularTypeParamConstraint {
+                            span: constraint.span(),
+                            name: param.name.clone(),
+                        }
+                        .into(),
+                    );
+                }
+            }
 
             let ctxt = self.ctx.module_id;
             let mut map = HashMap::default();
@@ -134,12 +151,17 @@ impl Analyzer<'_, '_> {
 
         let has_constraint = constraint.is_some();
 
+        let mut metadata = TypeParamMetadata::default();
+        if p.is_const {
+            metadata.common.flags.insert(TypeFlags::CONST);
+        }
+
         let param = TypeParam {
             span: p.span,
             name: p.name.clone().into(),
             constraint,
             default,
-            metadata: Default::default(),
+            metadata,
             tracker: Default::default(),
         };
         self.register_type(param.name.clone(), param.clone().into());
@@ -780,6 +802,17 @@ impl Analyzer<'_, '_> {
                 }
             }
 
+            RTsEntityName::Ident(ref i)
+                if &*i.sym == "NoInfer"
+                    && type_args.as_ref().map_or(false, |args| args.params.len() == 1)
+                    && self.find_type(&i.into())?.is_none() =>
+            {
+                // `NoInfer<T>` is `T`, but `T` is not used as an inference site.
+                let mut ty = type_args.unwrap().params.into_iter().next().unwrap();
+                ty.metadata_mut().flags.insert(TypeFlags::NO_INFER);
+                return Ok(ty);
+            }
+
             RTsEntityName::Ident(ref i) => {
                 self.report_error_for_type_param_usages_in_static_members(i);
 
@@ -1200,6 +1233,47 @@ impl Analyzer<'_, '_> {
         }
     }
 
+    /// Returns true if a pattern without a type (annotated or contextual)
+    /// should be reported as implicit `any`.
+    fn should_report_implicit_any_for_pat(&self) -> bool {
+        if self.ctx.is_calling_iife || !self.env.rule().no_implicit_any {
+            return false;
+        }
+
+        #[allow(clippy::nonminimal_bool)]
+        let no_type_ann = !self.ctx.in_argument && !(self.ctx.in_return_arg && self.ctx.in_fn_with_return_type) && !self.ctx.in_assign_rhs;
+
+        no_type_ann || self.ctx.in_useless_expr_for_seq || self.ctx.check_for_implicit_any
+    }
+
+    /// Reports binding elements of a destructuring parameter which are
+    /// implicitly typed as `any`.
+    ///
+    /// This should be called before [Self::default_any_pat], as the pattern
+    /// is filled with the default type by it.
+    pub(crate) fn report_implicit_any_in_binding_pat(&mut self, p: &RPat) {
+        let has_type = match p {
+            RPat::Array(RArrayPat { type_ann: Some(..), .. }) | RPat::Object(RObjectPat { type_ann: Some(..), .. }) => true,
+            RPat::Array(RArrayPat { node_id, .. }) | RPat::Object(RObjectPat { node_id, .. }) => match &self.mutations {
+                Some(m) => m.for_pats.get(node_id).map_or(false, |m| m.ty.is_some()),
+                None => false,
+            },
+            _ => return,
+        };
+
+        if has_type || !self.should_report_implicit_any_for_pat() {
+            return;
+        }
+
+        let mut spans = vec![];
+        collect_implicit_any_binding_elements(p, &mut spans);
+
+        for span in spans {
+            self.storage
+                .report(ErrorKind::ImplicitAny { span }.context("binding element without default type"));
+        }
+    }
+
     /// Handle implicit defaults.
     pub(crate) fn default_any_pat(&mut self, p: &RPat) {
         match p {
@@ -1222,14 +1296,9 @@ impl Analyzer<'_, '_> {
             }
         }
 
-        #[allow(clippy::nonminimal_bool)]
-        if !self.ctx.is_calling_iife && self.env.rule().no_implicit_any {
-            let no_type_ann =
-                !self.ctx.in_argument && !(self.ctx.in_return_arg && self.ctx.in_fn_with_return_type) && !self.ctx.in_assign_rhs;
-            if no_type_ann || self.ctx.in_useless_expr_for_seq || self.ctx.check_for_implicit_any {
-                self.storage
-                    .report(ErrorKind::ImplicitAny { span: i.id.span }.context("default type"));
-            }
+        if self.should_report_implicit_any_for_pat() {
+            self.storage
+                .report(ErrorKind::ImplicitAny { span: i.id.span }.context("default type"));
         }
 
         if let Some(m) = &mut self.mutations {
@@ -1410,3 +1479,55 @@ impl Analyzer<'_, '_> {
         }
     }
 }
+
+/// Returns `true` if the constraint of `param` refers back to `param` through
+/// type parameters of `params`, like `T` in `<T extends U, U extends T>`.
+fn has_circular_constraint(params: &[TypeParam], param: &TypeParam) -> bool {
+    let mut visited = vec![];
+    let mut cur = param;
+
+    while let Some(Type::Param(constraint)) = cur.constraint.as_deref().map(Type::normalize) {
+        if constraint.name == param.name {
+            return true;
+        }
+        if visited.contains(&constraint.name) {
+            return false;
+        }
+        visited.push(constraint.name.clone());
+
+        cur = match params.iter().find(|p| p.name == constraint.name) {
+            Some(p) => p,
+            None => return false,
+        };
+    }
+
+    false
+}
+
+fn collect_implicit_any_binding_elements(p: &RPat, spans: &mut Vec<Span>) {
+    match p {
+        RPat::Array(arr) => {
+            for elem in arr.elems.iter().flatten() {
+                match elem {
+                    RPat::Ident(RBindingIdent { id, type_ann: None, .. }) => spans.push(id.span),
+                    RPat::Array(..) | RPat::Object(..) => collect_implicit_any_binding_elements(elem, spans),
+                    _ => {}
+                }
+            }
+        }
+        RPat::Object(obj) => {
+            for prop in &obj.props {
+                match prop {
+                    RObjectPatProp::KeyValue(prop) => match &*prop.value {
+                        RPat::Ident(RBindingIdent { id, type_ann: None, .. }) => spans.push(id.span),
+                        RPat::Array(..) | RPat::Object(..) => collect_implicit_any_binding_elements(&prop.value, spans),
+                        _ => {}
+                    },
+                    RObjectPatProp::Assign(RAssignPatProp { key, value: None, .. }) => spans.push(key.span),
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+}
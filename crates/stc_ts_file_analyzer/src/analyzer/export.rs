@@ -116,7 +116,7 @@ impl Analyzer<'_, '_> {
         match export.decl {
             RDefaultDecl::Fn(ref f) => {
                 let i = f.ident.as_ref().map(|v| v.into()).unwrap_or_else(|| Id::word(js_word!("default")));
-                let fn_ty = match f.function.validate_with_args(self, f.ident.as_ref()) {
+                let fn_ty = match f.function.validate_with_args(self, (f.ident.as_ref(), None)) {
                     Ok(ty) => ty,
                     Err(err) => {
                         self.storage.report(err);
@@ -124,11 +124,7 @@ impl Analyzer<'_, '_> {
                     }
                 };
                 if f.function.return_type.is_none() {
-                    if let Some(m) = &mut self.mutations {
-                        if m.for_fns.entry(f.function.node_id).or_default().ret_ty.is_none() {
-                            m.for_fns.entry(f.function.node_id).or_default().ret_ty = Some(*fn_ty.ret_ty.clone());
-                        }
-                    }
+                    self.record_return_type_of_fn(f.function.span, f.function.node_id, &fn_ty.ret_ty);
                 }
 
                 self.declare_var(span, VarKind::Fn, i.clone(), Some(fn_ty.into()), None, true, true, false)
@@ -8,8 +8,8 @@ use stc_ts_errors::{
 };
 use stc_ts_type_ops::Fix;
 use stc_ts_types::{
-    type_id::SymbolId, Array, CommonTypeMetadata, ComputedKey, Intersection, Key, KeywordType, KeywordTypeMetadata, LitType, Symbol, Tuple,
-    TupleElement, Type, TypeParam, TypeParamInstantiation, Union, UnionMetadata,
+    type_id::SymbolId, Array, CommonTypeMetadata, ComputedKey, Intersection, Key, KeywordType, KeywordTypeMetadata, LitType, RestType,
+    Symbol, Tuple, TupleElement, Type, TypeParam, TypeParamInstantiation, Union, UnionMetadata,
 };
 use stc_utils::{
     cache::Freeze,
@@ -64,7 +64,11 @@ impl Analyzer<'_, '_> {
             .and_then(|ty| self.get_iterator(span, Cow::Borrowed(ty), Default::default()).ok());
         iterator.freeze();
 
-        let prefer_tuple = self.ctx.prefer_tuple_for_array_lit || self.prefer_tuple(type_ann.as_deref());
+        let is_tuple_type_ann = self.prefer_tuple(type_ann.as_deref());
+        let prefer_tuple = self.ctx.prefer_tuple_for_array_lit || is_tuple_type_ann;
+        // Spread arrays become rest elements, like `[1, ...number[]]`, only if the
+        // array literal is a tuple by itself.
+        let in_tuple_context = self.ctx.in_const_assertion || is_tuple_type_ann;
         let is_empty = elems.is_empty();
         let mut can_be_tuple = self.ctx.prefer_tuple_for_array_lit || !self.ctx.array_lit_cannot_be_tuple;
         let mut elements = Vec::with_capacity(elems.len());
@@ -103,6 +107,19 @@ impl Analyzer<'_, '_> {
                     // TODO(kdy1): PERF
 
                     match element_type {
+                        Type::Array(array) if in_tuple_context => {
+                            elements.push(TupleElement {
+                                span,
+                                label: None,
+                                ty: box Type::Rest(RestType {
+                                    span,
+                                    ty: box Type::Array(array),
+                                    metadata: Default::default(),
+                                    tracker: Default::default(),
+                                }),
+                                tracker: Default::default(),
+                            });
+                        }
                         Type::Array(array) => {
                             can_be_tuple = false;
                             elements.push(TupleElement {
@@ -213,6 +230,15 @@ impl Analyzer<'_, '_> {
             return Ok(ty);
         }
 
+        // `[...number[]]` is `number[]`.
+        if let [TupleElement { ty, .. }] = &*elements {
+            if let Type::Rest(RestType { ty: rest_ty, .. }) = ty.normalize() {
+                if let Type::Array(..) = rest_ty.normalize() {
+                    return Ok(*rest_ty.clone());
+                }
+            }
+        }
+
         let should_be_any = elements
             .iter()
             .all(|el| el.ty.is_kwd(TsKeywordTypeKind::TsNullKeyword) || el.ty.is_kwd(TsKeywordTypeKind::TsUndefinedKeyword));
@@ -75,6 +75,23 @@ impl Analyzer<'_, '_> {
 }
 
 impl Analyzer<'_, '_> {
+    /// Returns the awaited type of `ty` if it's `Promise<T>`, like the return
+    /// type of an async function.
+    pub(crate) fn awaited_type_of_promise(&mut self, span: Span, ty: &Type) -> VResult<Option<Type>> {
+        let is_promise =
+            unwrap_ref_with_single_arg(ty, "Promise").is_some() || matches!(ty.normalize(), Type::Interface(i) if i.name == "Promise");
+        if !is_promise {
+            return Ok(None);
+        }
+
+        let awaited = self.get_awaited_type(span, Cow::Borrowed(ty))?.into_owned();
+        // `Promise<Promise<T>>` is awaited to `T`.
+        match self.awaited_type_of_promise(span, &awaited)? {
+            Some(ty) => Ok(Some(ty)),
+            None => Ok(Some(awaited)),
+        }
+    }
+
     pub(crate) fn get_awaited_type<'a>(&mut self, span: Span, ty: Cow<'a, Type>) -> VResult<Cow<'a, Type>> {
         if let Some(arg) = unwrap_ref_with_single_arg(&ty, "Promise") {
             return self.get_awaited_type(span, Cow::Borrowed(arg)).map(Cow::into_owned).map(Cow::Owned);
@@ -19,8 +19,8 @@ use stc_ts_generics::type_param::finder::TypeParamUsageFinder;
 use stc_ts_type_ops::{generalization::prevent_generalize, is_str_lit_or_union, Fix};
 use stc_ts_types::{
     type_id::SymbolId, Alias, Array, Class, ClassDef, ClassMember, ClassProperty, CommonTypeMetadata, Function, Id, IdCtx,
-    IndexedAccessType, Instance, Interface, Intersection, Key, KeywordType, KeywordTypeMetadata, LitType, QueryExpr, QueryType, Ref,
-    StaticThis, Symbol, Union, UnionMetadata,
+    IndexedAccessType, Instance, Interface, Intersection, Key, KeywordType, KeywordTypeMetadata, LitType, Predicate, QueryExpr, QueryType,
+    Ref, StaticThis, Symbol, Union, UnionMetadata,
 };
 use stc_ts_utils::PatExt;
 use stc_utils::{cache::Freeze, ext::TypeVecExt};
@@ -123,7 +123,7 @@ impl Analyzer<'_, '_> {
 
         // TODO(kdy1): validate children
 
-        self.with_child(ScopeKind::Call, Default::default(), |analyzer: &mut Analyzer| {
+        let ret_ty = self.with_child(ScopeKind::Call, Default::default(), |analyzer: &mut Analyzer| {
             analyzer.ctx.is_calling_iife = is_callee_iife;
 
             analyzer.extract_call_new_expr_member(
@@ -135,7 +135,20 @@ impl Analyzer<'_, '_> {
                 type_args.as_deref(),
                 type_ann.as_deref(),
             )
-        })
+        })?;
+
+        // Assertion signatures can't be inferred, so the callee should be
+        // declared with an explicit type annotation.
+        if let Type::Predicate(Predicate { asserts: true, .. }) = ret_ty.normalize() {
+            if let RExpr::Ident(i) = &**callee {
+                if self.data.vars_without_type_ann.contains(&i.into()) {
+                    self.storage
+                        .report(ErrorKind::AssertionTargetWithoutTypeAnn { span: i.span }.into());
+                }
+            }
+        }
+
+        Ok(ret_ty)
     }
 }
 
@@ -2193,14 +2206,16 @@ impl Analyzer<'_, '_> {
         })
     }
 
-    fn validate_arg_count(
-        &mut self,
-        span: Span,
-        params: &[FnParam],
-        args: &[RExprOrSpread],
-        arg_types: &[TypeOrSpread],
-        spread_arg_types: &[TypeOrSpread],
-    ) -> VResult<()> {
+    /// Returns the minimum and maximum number of arguments a call to `f`
+    /// accepts.
+    ///
+    /// The maximum is [None] if `f` has a rest parameter which accepts any
+    /// number of arguments.
+    pub fn function_arity(&mut self, f: &Function) -> (usize, Option<usize>) {
+        self.arity_of_params(f.span.with_ctxt(SyntaxContext::empty()), &f.params)
+    }
+
+    fn arity_of_params(&mut self, span: Span, params: &[FnParam]) -> (usize, Option<usize>) {
         /// Count required parameter count.
         fn count_required_pat(p: &RPat) -> usize {
             match p {
@@ -2240,15 +2255,6 @@ impl Analyzer<'_, '_> {
             }
         }
 
-        // Assertion about deep clone
-        if cfg!(debug_assertions) {
-            let _p = params.to_vec();
-            let _a = arg_types.to_vec();
-            let _s = spread_arg_types.to_vec();
-        }
-
-        let span = span.with_ctxt(SyntaxContext::empty());
-
         let mut min_param: usize = params.iter().map(|v| &v.pat).map(count_required_pat).sum();
 
         let mut max_param = Some(params.len());
@@ -2319,6 +2325,28 @@ impl Analyzer<'_, '_> {
             }
         }
 
+        (min_param, max_param)
+    }
+
+    fn validate_arg_count(
+        &mut self,
+        span: Span,
+        params: &[FnParam],
+        args: &[RExprOrSpread],
+        arg_types: &[TypeOrSpread],
+        spread_arg_types: &[TypeOrSpread],
+    ) -> VResult<()> {
+        // Assertion about deep clone
+        if cfg!(debug_assertions) {
+            let _p = params.to_vec();
+            let _a = arg_types.to_vec();
+            let _s = spread_arg_types.to_vec();
+        }
+
+        let span = span.with_ctxt(SyntaxContext::empty());
+
+        let (min_param, max_param) = self.arity_of_params(span, params);
+
         let has_spread = args.iter().any(|arg| arg.spread.is_some());
         if has_spread {
             // TODO
@@ -3029,6 +3057,7 @@ impl Analyzer<'_, '_> {
                                     .map_err(|err| {
                                         ErrorKind::WrongArgType {
                                             span: arg.span(),
+                                            param_span: param.span,
                                             inner: box err,
                                         }
                                         .into()
@@ -3069,6 +3098,7 @@ impl Analyzer<'_, '_> {
                                         )
                                         .convert_err(|err| ErrorKind::WrongArgType {
                                             span: arg.span(),
+                                            param_span: param.span,
                                             inner: box err.into(),
                                         })
                                         .context("tried to assign to element of a tuple type of a parameter");
@@ -3116,6 +3146,7 @@ impl Analyzer<'_, '_> {
                             let err = err
                                 .convert(|err| ErrorKind::WrongArgType {
                                     span: arg.span(),
+                                    param_span: param.span,
                                     inner: box err.into(),
                                 })
                                 .context("tried assigning elem type of an array because parameter is declared as a rest pattern");
@@ -3168,6 +3199,7 @@ impl Analyzer<'_, '_> {
                         )
                         .convert_err(|err| ErrorKind::WrongArgType {
                             span: err.span(),
+                            param_span: param.span,
                             inner: box err.into(),
                         })
                         .context("arg is spread");
@@ -3207,6 +3239,7 @@ impl Analyzer<'_, '_> {
                                                 .map(|err| {
                                                     ErrorKind::WrongArgType {
                                                         span: err.span(),
+                                                        param_span: param.span,
                                                         inner: box err.clone(),
                                                     }
                                                     .into()
@@ -3220,6 +3253,7 @@ impl Analyzer<'_, '_> {
 
                             ErrorKind::WrongArgType {
                                 span: arg.span(),
+                                param_span: param.span,
                                 inner: box err.into(),
                             }
                         });
@@ -3242,40 +3276,86 @@ impl Analyzer<'_, '_> {
     /// should make type of `subscriber` `SafeSubscriber`, not `Subscriber`.
     /// I (kdy1) don't know why.
     fn add_call_facts(&mut self, params: &[FnParam], args: &[RExprOrSpread], ret_ty: &mut Type) {
-        if !self.ctx.in_cond {
+        let p = match ret_ty.normalize() {
+            Type::Predicate(p) => p,
+            _ => return,
+        };
+
+        // Assertion signatures narrow the code after the call, not only the
+        // branches of a condition.
+        if !self.ctx.in_cond && !p.asserts {
             return;
         }
 
-        if let Type::Predicate(p) = ret_ty.normalize() {
-            let ty = match &p.ty {
-                Some(v) => v,
-                None => return,
-            };
-
-            match &p.param_name {
-                RTsThisTypeOrIdent::TsThisType(this) => {
-                    //
+        match &p.param_name {
+            RTsThisTypeOrIdent::TsThisType(this) => {
+                if let Some(ty) = &p.ty {
                     self.store_call_fact_for_var(this.span, Id::word("this".into()), &ty.clone().freezed());
                 }
-                RTsThisTypeOrIdent::Ident(arg_id) => {
-                    for (idx, param) in params.iter().enumerate() {
-                        match &param.pat {
-                            RPat::Ident(i) if i.id.sym == arg_id.sym => {
-                                // TODO(kdy1): Check length of args.
-                                let arg = &args[idx];
-                                if let RExpr::Ident(var_name) = &*arg.expr {
-                                    let ty = ty.clone().freezed();
-                                    self.store_call_fact_for_var(var_name.span, var_name.into(), &ty);
+            }
+            RTsThisTypeOrIdent::Ident(arg_id) => {
+                // `this` parameter does not have a corresponding argument.
+                let params = params.iter().filter(|param| match &param.pat {
+                    RPat::Ident(i) => i.id.sym != js_word!("this"),
+                    _ => true,
+                });
+
+                for (idx, param) in params.enumerate() {
+                    match &param.pat {
+                        RPat::Ident(i) if i.id.sym == arg_id.sym => {
+                            let arg = match args.get(idx) {
+                                Some(arg) if arg.spread.is_none() => arg,
+                                _ => continue,
+                            };
+
+                            match &p.ty {
+                                Some(ty) => {
+                                    if let RExpr::Ident(var_name) = &*arg.expr {
+                                        let ty = ty.clone().freezed();
+                                        self.store_call_fact_for_var(var_name.span, var_name.into(), &ty);
+                                    }
                                 }
+                                // `asserts cond`
+                                None => self.add_facts_of_asserted_arg(&arg.expr),
                             }
-                            _ => {}
                         }
+                        _ => {}
                     }
                 }
             }
         }
     }
 
+    /// Adds facts which hold if `arg` is truthy, for `asserts cond`.
+    ///
+    /// ```ts
+    /// declare function assert(cond: unknown): asserts cond;
+    ///
+    /// assert(typeof x === "string");
+    /// ```
+    fn add_facts_of_asserted_arg(&mut self, arg: &RExpr) {
+        let facts = self
+            .with_child(ScopeKind::Flow, Default::default(), |child: &mut Analyzer| {
+                // The argument is already validated, so errors are reported only once.
+                child.ctx.ignore_errors = true;
+                child.ctx.in_cond = true;
+                child.ctx.should_store_truthy_for_access = true;
+
+                let prev_facts = child.cur_facts.take();
+                let res = arg.validate_with_args(child, (TypeOfMode::RValue, None, None));
+                let facts = child.cur_facts.true_facts.take();
+                child.cur_facts = prev_facts;
+
+                res?;
+                Ok(facts)
+            })
+            .report(&mut self.storage);
+
+        if let Some(facts) = facts {
+            self.cur_facts.true_facts += facts;
+        }
+    }
+
     fn narrow_with_predicate(&mut self, span: Span, orig_ty: &Type, new_ty: Type) -> VResult<Type> {
         let _tracing = if cfg!(debug_assertions) {
             Some(tracing::span!(tracing::Level::ERROR, "narrow_with_predicate").entered())
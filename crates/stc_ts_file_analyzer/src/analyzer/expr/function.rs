@@ -1,17 +1,19 @@
 use std::borrow::Cow;
 
-use stc_ts_ast_rnode::{RArrowExpr, RBlockStmtOrExpr, RNumber, RPat};
+use stc_ts_ast_rnode::{RArrowExpr, RBindingIdent, RBlockStmtOrExpr, RIdent, RNumber, RPat};
+use stc_ts_errors::{DebugExt, ErrorKind};
 use stc_ts_types::{
     type_id::DestructureId, Class, ClassMetadata, Function, Key, KeywordType, RestType, Tuple, TupleElement, Type, TypeParam, Union,
 };
 use stc_ts_utils::PatExt;
 use stc_utils::cache::Freeze;
+use swc_atoms::js_word;
 use swc_common::{Span, Spanned};
 use swc_ecma_ast::{EsVersion, TsKeywordTypeKind};
 
 use super::call_new::ExtractKind;
 use crate::{
-    analyzer::{assign::AssignOpts, expr::TypeOfMode, pat::PatMode, Analyzer, Ctx, ScopeKind},
+    analyzer::{assign::AssignOpts, expr::TypeOfMode, pat::PatMode, stmt::return_type::promise_type, Analyzer, Ctx, ScopeKind},
     ty::TypeExt,
     validator,
     validator::ValidateWith,
@@ -23,12 +25,17 @@ impl Analyzer<'_, '_> {
     fn validate(&mut self, f: &RArrowExpr, type_ann: Option<&Type>) -> VResult<Function> {
         let marks = self.marks();
 
-        let type_ann = self.expand_type_ann(f.span, type_ann)?;
+        let type_ann = self.expand_type_ann(f.span, type_ann)?.map(Cow::into_owned);
+
+        let mut a = self.with_contextual_type(type_ann);
+        a.with_child(ScopeKind::ArrowFn, Default::default(), |child: &mut Analyzer| {
+            let type_ann = child.contextual_type().cloned();
 
-        self.with_child(ScopeKind::ArrowFn, Default::default(), |child: &mut Analyzer| {
             if child.env.target() <= EsVersion::Es5 && child.ctx.in_static_property_initializer {
                 child.ctx.super_references_super_class = false;
             }
+            child.ctx.in_async = f.is_async;
+            child.ctx.in_generator = false;
 
             let type_params = try_opt!(f.type_params.validate_with(child));
 
@@ -40,9 +47,20 @@ impl Analyzer<'_, '_> {
                     ..child.ctx
                 };
 
-                child.apply_fn_type_ann(f.span, f.params.iter(), type_ann.as_deref());
+                for p in &f.params {
+                    if let RPat::Ident(RBindingIdent {
+                        id: RIdent { sym: js_word!("this"), .. },
+                        ..
+                    }) = p
+                    {
+                        child.storage.report(ErrorKind::ThisParamInArrowFn { span: p.span() }.into());
+                    }
+                }
+
+                child.apply_fn_type_ann(f.span, f.params.iter(), type_ann.as_ref());
 
                 for p in &f.params {
+                    child.report_implicit_any_in_binding_pat(p);
                     child.default_any_pat(p);
                 }
                 f.params.validate_with(&mut *child.with_ctx(ctx))?
@@ -75,14 +93,40 @@ impl Analyzer<'_, '_> {
             }
             .freezed();
 
+            if f.is_async {
+                if let Some(ty) = &declared_ret_ty {
+                    child.validate_return_type_of_async_fn(ty);
+                }
+            }
+
+            let contextual_ret_ty = if f.return_type.is_none() {
+                child.get_return_type_from_fn_type_ann(f.span, type_ann.as_ref())
+            } else {
+                None
+            };
+            child.scope.contextual_return_type = contextual_ret_ty.clone();
+
             let inferred_return_type = {
                 match f.body {
                     RBlockStmtOrExpr::Expr(ref e) => Some({
-                        let ty = e.validate_with_args(child, (TypeOfMode::RValue, None, declared_ret_ty.as_ref()))?;
-                        if !child.ctx.in_argument && f.return_type.is_none() && type_ann.is_none() && child.may_generalize(&ty) {
+                        let ty = e.validate_with_args(
+                            child,
+                            (TypeOfMode::RValue, None, declared_ret_ty.as_ref().or(contextual_ret_ty.as_ref())),
+                        )?;
+                        let ty = if !child.ctx.in_argument && f.return_type.is_none() && type_ann.is_none() && child.may_generalize(&ty) {
                             ty.generalize_lit()
                         } else {
                             ty
+                        };
+
+                        if f.is_async {
+                            let ty = child
+                                .get_awaited_type(e.span(), Cow::Owned(ty))
+                                .context("tried to get the awaited type of the body of an async arrow function")?
+                                .into_owned();
+                            promise_type(f.span, ty)
+                        } else {
+                            ty
                         }
                     }),
                     RBlockStmtOrExpr::BlockStmt(ref s) => child.visit_stmts_for_return(f.span, f.is_async, f.is_generator, &s.stmts)?,
@@ -137,6 +181,22 @@ impl Analyzer<'_, '_> {
 }
 
 impl Analyzer<'_, '_> {
+    /// Returns the return type of the contextual signature, if `type_ann` has
+    /// exactly one non-generic call signature.
+    pub(crate) fn get_return_type_from_fn_type_ann(&mut self, span: Span, type_ann: Option<&Type>) -> Option<Type> {
+        let candidates = self.extract_callee_candidates(span, ExtractKind::Call, type_ann?).ok()?;
+        if candidates.len() != 1 {
+            return None;
+        }
+
+        let candidate = candidates.into_iter().next()?;
+        if candidate.type_params.is_some() {
+            return None;
+        }
+
+        Some(candidate.ret_ty.freezed())
+    }
+
     pub(crate) fn apply_fn_type_ann<'a>(&mut self, span: Span, params: impl Iterator<Item = &'a RPat> + Clone, type_ann: Option<&Type>) {
         if let Some(ty) = &type_ann {
             // See functionExpressionContextualTyping1.ts
@@ -162,6 +222,15 @@ impl Analyzer<'_, '_> {
             for param in candidates[0].params.iter() {
                 match param.pat {
                     RPat::Rest(..) => {
+                        // Elements of a tuple type are used as parameters, like
+                        // `(...args: [number, string]) => void`.
+                        if let Ok(ty) = self.normalize(Some(span), Cow::Borrowed(&param.ty), Default::default()) {
+                            if let Type::Tuple(tuple) = ty.normalize() {
+                                params_tuple_els.extend(tuple.elems.iter().cloned());
+                                continue;
+                            }
+                        }
+
                         params_tuple_els.push(TupleElement {
                             span: param.span,
                             label: None,
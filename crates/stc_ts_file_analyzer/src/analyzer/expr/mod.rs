@@ -21,9 +21,10 @@ use stc_ts_generics::ExpandGenericOpts;
 use stc_ts_type_ops::{generalization::prevent_generalize, is_str_lit_or_union, Fix};
 pub use stc_ts_types::IdCtx;
 use stc_ts_types::{
-    name::Name, Alias, Class, ClassDef, ClassMember, ClassProperty, CommonTypeMetadata, ComputedKey, ConstructorSignature, Id, Key,
-    KeywordType, KeywordTypeMetadata, LitType, LitTypeMetadata, Method, Module, ModuleTypeData, Operator, OptionalType, PropertySignature,
-    QueryExpr, QueryType, QueryTypeMetadata, StaticThis, ThisType, TplElem, TplType, TplTypeMetadata, TypeParamInstantiation,
+    name::Name, Alias, Class, ClassDef, ClassMember, ClassProperty, CommonTypeMetadata, ComputedKey, ConstructorSignature,
+    FunctionMetadata, Id, Key, KeywordType, KeywordTypeMetadata, LitType, LitTypeMetadata, Method, Module, ModuleTypeData, Operator,
+    OptionalType, PropertySignature, QueryExpr, QueryType, QueryTypeMetadata, StaticThis, ThisType, TplElem, TplType, TplTypeMetadata,
+    TypeParamInstantiation,
 };
 use stc_utils::{cache::Freeze, debug_ctx, ext::TypeVecExt, stack};
 use swc_atoms::js_word;
@@ -866,7 +867,7 @@ impl Analyzer<'_, '_> {
                                 type_params: m.type_params.clone(),
                                 params: m.params.clone(),
                                 ret_ty: m.ret_ty.clone().unwrap_or_else(|| box Type::any(span, Default::default())),
-                                metadata: Default::default(),
+                                metadata: FunctionMetadata::method(),
                                 tracker: Default::default(),
                             });
 
@@ -1438,7 +1439,7 @@ impl Analyzer<'_, '_> {
                                         type_params: member.type_params.clone(),
                                         params: member.params.clone(),
                                         ret_ty: member.ret_ty.clone(),
-                                        metadata: Default::default(),
+                                        metadata: FunctionMetadata::method(),
                                         tracker: Default::default(),
                                     }));
                                 }
@@ -1505,7 +1506,7 @@ impl Analyzer<'_, '_> {
                                             type_params: member.type_params.clone(),
                                             params: member.params.clone(),
                                             ret_ty: member.ret_ty.clone(),
-                                            metadata: Default::default(),
+                                            metadata: FunctionMetadata::method(),
                                             tracker: Default::default(),
                                         }));
                                     }
@@ -1573,7 +1574,7 @@ impl Analyzer<'_, '_> {
                                         type_params: member.type_params.clone(),
                                         params: member.params.clone(),
                                         ret_ty: member.ret_ty.clone(),
-                                        metadata: Default::default(),
+                                        metadata: FunctionMetadata::method(),
                                         tracker: Default::default(),
                                     }));
                                 }
@@ -1944,7 +1945,7 @@ impl Analyzer<'_, '_> {
                                     type_params: mtd.type_params.clone(),
                                     params: mtd.params.clone(),
                                     ret_ty: mtd.ret_ty.clone(),
-                                    metadata: Default::default(),
+                                    metadata: FunctionMetadata::method(),
                                     tracker: Default::default(),
                                 }));
                             }
@@ -2727,7 +2728,7 @@ impl Analyzer<'_, '_> {
                                     type_params: m.type_params.clone(),
                                     params: m.params.clone(),
                                     ret_ty: m.ret_ty.clone(),
-                                    metadata: Default::default(),
+                                    metadata: FunctionMetadata::method(),
                                     tracker: Default::default(),
                                 }));
                             }
@@ -3699,6 +3700,27 @@ impl Analyzer<'_, '_> {
                 self.storage.report(ErrorKind::BlockScopedVarUsedBeforeInit { span }.into())
             })();
 
+            // Initializers of parameters can only reference parameters declared before
+            // them, unless the reference is deferred by a nested function.
+            if let Some(scope) = self.scope.first(|scope| {
+                if scope.declaring_parameters.contains(&i.into()) {
+                    return true;
+                }
+
+                matches!(
+                    scope.kind(),
+                    ScopeKind::Method { .. } | ScopeKind::Fn | ScopeKind::ArrowFn | ScopeKind::Constructor
+                )
+            }) {
+                if scope.declaring_parameters.contains(&i.into()) {
+                    if scope.declaring.contains(&i.into()) {
+                        self.storage.report(ErrorKind::ReferencedInInit { span }.into());
+                    } else {
+                        self.storage.report(ErrorKind::ParamReferencesLaterParam { span }.into());
+                    }
+                }
+            }
+
             if self.scope.can_access_declaring_regardless_of_context(&i.into()) {
                 return Ok(Type::any(span, Default::default()));
             }
@@ -3716,6 +3738,11 @@ impl Analyzer<'_, '_> {
 
         // At here, it cannot be a declared variable.
         if let js_word!("arguments") = i.sym {
+            if self.scope.is_arguments_in_class_initializer() {
+                self.storage.report(ErrorKind::ArgumentsInPropertyInitializer { span }.into());
+                return Ok(Type::any(span, Default::default()));
+            }
+
             if self.env.target() <= EsVersion::Es5 {
                 // `arguments` cannot be used as implicit variable if target <= ES5
                 let arguments_point_to_arrow = Some(true)
@@ -3740,6 +3767,16 @@ impl Analyzer<'_, '_> {
                 }
             }
 
+            if self.scope.is_arguments_implicitly_defined() {
+                return Ok(Type::Ref(Ref {
+                    span,
+                    type_name: RTsEntityName::Ident(RIdent::new("IArguments".into(), span)),
+                    type_args: None,
+                    metadata: Default::default(),
+                    tracker: Default::default(),
+                }));
+            }
+
             return Ok(Type::any(span, Default::default()));
         }
 
@@ -13,6 +13,7 @@ use tracing::debug;
 
 use crate::{
     analyzer::{Analyzer, NormalizeTypeOpts, ScopeKind},
+    util::unwrap_ref_with_single_arg,
     validator::ValidateWith,
     VResult,
 };
@@ -25,10 +26,13 @@ pub struct AppendTypeOpts {
 #[validator]
 impl Analyzer<'_, '_> {
     fn validate(&mut self, node: &RObjectLit, type_ann: Option<&Type>) -> VResult<Type> {
+        let this_from_this_type = type_ann.and_then(|ty| self.this_type_arg(node.span, ty)).freezed();
         let type_ann = self.expand_type_ann(node.span, type_ann)?;
         debug_assert_eq!(node.span.ctxt, SyntaxContext::empty());
 
         self.with_child(ScopeKind::ObjectLit, Default::default(), |a: &mut Analyzer| {
+            a.scope.this_from_this_type = this_from_this_type;
+
             let mut ret = Type::TypeLit(TypeLit {
                 span: node.span,
                 members: vec![],
@@ -39,6 +43,11 @@ impl Analyzer<'_, '_> {
             let mut known_keys = vec![];
             for prop in node.props.iter() {
                 ret = a.append_prop_or_spread_to_type(&mut known_keys, ret, prop, type_ann.as_deref())?;
+
+                // `this` in methods resolves to the properties declared so far.
+                if let Type::TypeLit(lit) = ret.normalize() {
+                    a.scope.this_object_members = lit.members.clone();
+                }
             }
 
             a.validate_type_literals(&ret, false);
@@ -49,6 +58,33 @@ impl Analyzer<'_, '_> {
 }
 
 impl Analyzer<'_, '_> {
+    /// Returns `T` of `ThisType<T>` in the contextual type of an object
+    /// literal.
+    ///
+    /// ```ts
+    /// declare function define(options: { data: Data } & ThisType<Data>): void;
+    /// ```
+    fn this_type_arg(&mut self, span: Span, ty: &Type) -> Option<Type> {
+        if let Some(arg) = unwrap_ref_with_single_arg(ty, "ThisType") {
+            return Some(arg.clone());
+        }
+
+        match ty.normalize() {
+            Type::Intersection(i) => i.types.iter().find_map(|ty| self.this_type_arg(span, ty)),
+
+            Type::Ref(..) => {
+                let expanded = self.normalize(Some(span), Cow::Borrowed(ty), Default::default()).ok()?.into_owned();
+
+                match expanded.normalize() {
+                    Type::Intersection(..) => self.this_type_arg(span, &expanded),
+                    _ => None,
+                }
+            }
+
+            _ => None,
+        }
+    }
+
     /// Object literals in unions are normalized upon widening.
     ///
     ///```ts
@@ -0,0 +1,154 @@
+use std::borrow::Cow;
+
+use stc_ts_ast_rnode::{RAssignPat, RBindingIdent, RFunction, RParam, RPat, RRestPat};
+use stc_ts_errors::ErrorKind;
+use stc_ts_types::{Function, Type};
+use stc_ts_utils::{
+    jsdoc::{find_deprecated_in_jsdoc, find_overloads_in_jsdoc, find_param_types_in_jsdoc, JsDocReturnType},
+    PatExt,
+};
+use stc_utils::cache::Freeze;
+
+use crate::{
+    analyzer::{util::ResultExt, Analyzer},
+    ty::builder::FunctionTypeBuilder,
+};
+
+impl Analyzer<'_, '_> {
+    /// Returns parameters of `f`, with the types of `@param` JSDoc tags applied
+    /// to parameters without a type annotation.
+    ///
+    /// Tags are matched by name, and tags for destructuring patterns are
+    /// matched by position.
+    pub(super) fn params_with_jsdoc_types<'a>(&mut self, f: &'a RFunction) -> Cow<'a, Vec<RParam>> {
+        if self.config.is_builtin || !self.rule().use_jsdoc_types {
+            return Cow::Borrowed(&f.params);
+        }
+
+        let tags = find_param_types_in_jsdoc(&self.comments, f.span.lo);
+        if tags.is_empty() {
+            return Cow::Borrowed(&f.params);
+        }
+
+        let mut params = f.params.clone();
+
+        for (idx, tag) in tags.into_iter().enumerate() {
+            let param = match params.iter().position(|p| name_of_param(&p.pat) == Some(&*tag.name)) {
+                Some(i) => &mut params[i],
+                None => match params.get_mut(idx) {
+                    Some(p) if name_of_param(&p.pat).is_none() => p,
+                    _ => {
+                        self.storage
+                            .report(ErrorKind::JsDocParamNameNotFound { span: tag.name_span }.into());
+                        continue;
+                    }
+                },
+            };
+
+            let pat = match &mut param.pat {
+                RPat::Assign(RAssignPat { left, .. }) => &mut **left,
+                pat => pat,
+            };
+            if pat.get_ty().is_some() {
+                continue;
+            }
+
+            if let Some((ty, _)) = self.type_from_jsdoc("@param", &tag.text, tag.pos) {
+                pat.set_ty(Some(box ty));
+
+                if tag.optional {
+                    if let RPat::Ident(i) = pat {
+                        i.id.optional = true;
+                    }
+                }
+            }
+        }
+
+        Cow::Owned(params)
+    }
+
+    /// Returns the overload signatures of `f` declared with `@overload` JSDoc
+    /// tags.
+    ///
+    /// Types which cannot be resolved and missing `@returns` tags default to
+    /// `any`.
+    pub(super) fn jsdoc_overloads_of_fn(&mut self, f: &RFunction) -> Option<Type> {
+        if self.config.is_builtin || !self.rule().use_jsdoc_types {
+            return None;
+        }
+
+        let overloads = find_overloads_in_jsdoc(&self.comments, f.span.lo);
+        if overloads.is_empty() {
+            return None;
+        }
+
+        let mut types = vec![];
+
+        for overload in overloads {
+            let mut builder = FunctionTypeBuilder::new(overload.span);
+
+            for tag in overload.params {
+                let ty = self
+                    .type_from_jsdoc("@param", &tag.text, tag.pos)
+                    .map(|(_, ty)| ty)
+                    .unwrap_or_else(|| Type::any(tag.name_span, Default::default()));
+
+                builder = if tag.optional {
+                    builder.optional_param(&*tag.name, ty)
+                } else {
+                    builder.param(&*tag.name, ty)
+                };
+            }
+
+            let ret_ty = match overload.return_type {
+                Some(JsDocReturnType::Type { text, pos }) => self.type_from_jsdoc("@returns", &text, pos).map(|(_, ty)| ty),
+                _ => None,
+            };
+            builder = builder.ret_ty(ret_ty.unwrap_or_else(|| Type::any(overload.span, Default::default())));
+
+            if let Some(ty) = builder.build().report(&mut self.storage) {
+                types.push(Type::Function(ty).freezed());
+            }
+        }
+
+        if types.is_empty() {
+            return None;
+        }
+
+        Some(Type::union(types).freezed())
+    }
+}
+
+/// Returns the name of a parameter which is not a destructuring pattern.
+fn name_of_param(pat: &RPat) -> Option<&str> {
+    match pat {
+        RPat::Ident(RBindingIdent { id, .. }) => Some(&*id.sym),
+        RPat::Assign(RAssignPat { left, .. }) | RPat::Rest(RRestPat { arg: left, .. }) => name_of_param(left),
+        _ => None,
+    }
+
+    /// Returns the message of the `@deprecated` tag of the declaration `f` is
+    /// created from.
+    ///
+    /// The message is empty if the tag does not have one, and [None] is
+    /// returned if `f` is not deprecated.
+    pub fn deprecation_message(&self, f: &Function) -> Option<&str> {
+        if !f.metadata.is_deprecated() {
+            return None;
+        }
+
+        self.data.deprecation_messages.get(&f.span).map(|message| &**message)
+    }
+
+    /// Records the message of the `@deprecated` tag of `f`, and returns `true`
+    /// if `f` has the tag.
+    pub(super) fn record_deprecation_of_fn(&mut self, f: &RFunction) -> bool {
+        match find_deprecated_in_jsdoc(&self.comments, f.span.lo) {
+            Some(tag) => {
+                self.data.deprecation_messages.insert(f.span, tag.message);
+                true
+            }
+            None => false,
+        }
+    }
+}
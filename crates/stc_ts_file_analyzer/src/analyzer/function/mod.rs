@@ -1,20 +1,32 @@
 use std::borrow::Cow;
 
-use rnode::{Fold, FoldWith};
-use stc_ts_ast_rnode::{RBindingIdent, RFnDecl, RFnExpr, RFunction, RIdent, RParamOrTsParamProp, RPat, RTsEntityName};
-use stc_ts_errors::{ErrorKind, Errors};
+use fxhash::{FxHashMap, FxHashSet};
+use rnode::{Fold, FoldWith, Visit, VisitWith};
+use stc_ts_ast_rnode::{
+    RArrayPat, RBindingIdent, RFnDecl, RFnExpr, RFunction, RIdent, RObjectPat, RParamOrTsParamProp, RPat, RRestPat, RTsEntityName,
+    RTsThisTypeOrIdent,
+};
+use stc_ts_errors::{DebugExt, ErrorKind, Errors};
 use stc_ts_type_ops::Fix;
 use stc_ts_types::{
-    Alias, CallSignature, Class, ClassDef, ClassMetadata, Function, Id, Interface, KeywordType, KeywordTypeMetadata, Ref, TypeElement,
+    Alias, CallSignature, Class, ClassDef, ClassMetadata, EnumVariant, Function, FunctionMetadata, Id, Interface, KeywordType,
+    KeywordTypeMetadata, LitType, Ref, TupleMetadata, TypeElement, TypeFlags, TypeParamInstantiation,
 };
 use stc_ts_utils::find_ids_in_pat;
 use stc_utils::cache::Freeze;
+use swc_atoms::{js_word, JsWord};
 use swc_common::{Span, Spanned, SyntaxContext};
 use swc_ecma_ast::TsKeywordTypeKind;
 use ty::TypeExt;
 
 use crate::{
-    analyzer::{pat::PatMode, scope::VarKind, util::ResultExt, Analyzer, Ctx, ScopeKind},
+    analyzer::{
+        assign::AssignOpts,
+        pat::{is_param_required, PatMode},
+        scope::{VarInfo, VarKind},
+        util::ResultExt,
+        Analyzer, Ctx, ScopeKind,
+    },
     ty,
     ty::{FnParam, Tuple, Type, TypeParam},
     validator,
@@ -22,13 +34,47 @@ use crate::{
     VResult,
 };
 
+mod jsdoc;
 mod return_type;
 
+/// The context which changes the type of a function, used as the key of
+/// cached types of a function.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(super) struct FnCacheKey {
+    pub in_declare: bool,
+    pub in_argument: bool,
+    /// The function whose name is resolved as `typeof name`.
+    pub declaring_fn: Option<Id>,
+}
+
 #[validator]
 impl Analyzer<'_, '_> {
-    fn validate(&mut self, f: &RFunction, name: Option<&RIdent>) -> VResult<ty::Function> {
+    fn validate(&mut self, f: &RFunction, name: Option<&RIdent>, contextual_ret_ty: Option<&Type>) -> VResult<ty::Function> {
         let marks = self.marks();
 
+        // Results depending on contextual types or dropped errors can't be reused.
+        let cache_key = if !self.config.is_builtin
+            && !f.node_id.is_invalid()
+            && contextual_ret_ty.is_none()
+            && self.contextual_type().is_none()
+            && !self.ctx.reevaluating()
+            && !self.ctx.ignore_errors
+            && !self.has_contextual_param_types(f)
+        {
+            Some(FnCacheKey {
+                in_declare: self.ctx.in_declare,
+                in_argument: self.ctx.in_argument,
+                declaring_fn: self.scope.declaring_fn.as_ref().map(|(id, _)| id.clone()),
+            })
+        } else {
+            None
+        };
+        if let Some(key) = &cache_key {
+            if let Some(ty) = self.data.fn_types.get(&f.node_id).and_then(|types| types.get(key)) {
+                return Ok(ty.clone());
+            }
+        }
+
         if !self.ctx.reevaluating() && !self.ctx.ignore_errors && f.body.is_some() {
             if let Some(id) = name {
                 let v = self.data.fn_impl_spans.entry(id.into()).or_default();
@@ -43,7 +89,7 @@ impl Analyzer<'_, '_> {
             }
         }
 
-        self.with_child(ScopeKind::Fn, Default::default(), |child: &mut Analyzer| {
+        let fn_ty = self.with_child(ScopeKind::Fn, Default::default(), |child: &mut Analyzer| {
             child.ctx.allow_new_target = true;
             child.ctx.in_fn_with_return_type = f.return_type.is_some();
             child.ctx.in_async = f.is_async;
@@ -59,36 +105,103 @@ impl Analyzer<'_, '_> {
                 // Validate params
                 // TODO(kdy1): Move this to parser
                 let mut has_optional = false;
-                for p in &f.params {
-                    if has_optional {
+                let mut has_rest = false;
+                for (idx, p) in f.params.iter().enumerate() {
+                    match &p.pat {
+                        RPat::Ident(RBindingIdent {
+                            id:
+                                RIdent {
+                                    sym: js_word!("this"),
+                                    optional,
+                                    ..
+                                },
+                            ..
+                        }) => {
+                            if idx != 0 {
+                                child.storage.report(ErrorKind::ThisParamMustBeFirst { span: p.span() }.into());
+                            }
+
+                            if *optional {
+                                child.storage.report(ErrorKind::ThisParamCannotBeOptional { span: p.span() }.into());
+                            }
+                        }
+
+                        RPat::Rest(RRestPat { arg, .. })
+                            if matches!(
+                                &**arg,
+                                RPat::Ident(RBindingIdent {
+                                    id: RIdent { sym: js_word!("this"), .. },
+                                    ..
+                                })
+                            ) =>
+                        {
+                            child.storage.report(ErrorKind::ThisParamCannotBeRest { span: p.span() }.into());
+                        }
+
+                        _ => {}
+                    }
+
+                    if has_rest {
+                        // A rest parameter must be last in a parameter list.
+                        child.storage.report(ErrorKind::TS1014 { span: p.span() }.into());
+                        continue;
+                    }
+
+                    if let RPat::Rest(..) = p.pat {
+                        has_rest = true;
+                    }
+
+                    let is_optional = matches!(
+                        p.pat,
+                        RPat::Ident(RBindingIdent {
+                            id: RIdent { optional: true, .. },
+                            ..
+                        }) | RPat::Array(RArrayPat { optional: true, .. })
+                            | RPat::Object(RObjectPat { optional: true, .. })
+                    );
+
+                    if has_optional && !is_optional {
                         match p.pat {
-                            RPat::Ident(RBindingIdent {
-                                id: RIdent { optional: true, .. },
-                                ..
-                            })
-                            | RPat::Rest(..) => {}
+                            RPat::Assign(..) | RPat::Rest(..) => {}
                             _ => {
                                 child.storage.report(ErrorKind::TS1016 { span: p.span() }.into());
                             }
                         }
                     }
 
-                    if let RPat::Ident(RBindingIdent {
-                        id: RIdent { optional, .. },
-                        ..
-                    }) = p.pat
-                    {
-                        // Allow optional after optional parameter
-                        if optional {
-                            has_optional = true;
+                    // Allow optional after optional parameter
+                    if is_optional {
+                        has_optional = true;
+                    }
+                }
+
+                if !child.ctx.in_declare {
+                    let ids: Vec<RIdent> = find_ids_in_pat(&f.params);
+                    let mut spans_by_name = FxHashMap::<JsWord, Vec<Span>>::default();
+                    for id in &ids {
+                        spans_by_name.entry(id.sym.clone()).or_default().push(id.span);
+                    }
+
+                    for id in ids {
+                        if spans_by_name[&id.sym].len() >= 2 {
+                            child.storage.report(
+                                ErrorKind::DuplicateName {
+                                    span: id.span,
+                                    name: id.into(),
+                                }
+                                .into(),
+                            );
                         }
                     }
                 }
             }
 
             let type_params = try_opt!(f.type_params.validate_with(child));
+            child.report_unused_type_params(f);
 
             let params = {
+                let jsdoc_params = child.params_with_jsdoc_types(f);
+
                 let prev_len = child.scope.declaring_parameters.len();
                 let ids: Vec<Id> = find_ids_in_pat(&f.params);
                 child.scope.declaring_parameters.extend(ids);
@@ -100,11 +213,32 @@ impl Analyzer<'_, '_> {
                     is_fn_param: true,
                     ..child.ctx
                 };
-                let res = f.params.validate_with(&mut *child.with_ctx(ctx));
+                let mut params = {
+                    let mut a = child.with_ctx(ctx);
+
+                    // A parameter which cannot be validated is treated as `any`, so other
+                    // parameters and the body are still validated.
+                    jsdoc_params
+                        .iter()
+                        .map(|param| {
+                            param.validate_with(&mut *a).unwrap_or_else(|err| {
+                                a.storage.report(err);
+
+                                FnParam {
+                                    span: param.span,
+                                    required: is_param_required(&param.pat),
+                                    pat: param.pat.clone(),
+                                    ty: box Type::any(param.span, Default::default()),
+                                }
+                            })
+                        })
+                        .collect::<Vec<_>>()
+                };
 
                 child.scope.declaring_parameters.truncate(prev_len);
 
-                res?
+                child.mark_params_with_default_followed_by_required_as_required(&mut params);
+                params
             };
 
             let mut declared_ret_ty = {
@@ -112,11 +246,34 @@ impl Analyzer<'_, '_> {
                     in_actual_type: true,
                     ..child.ctx
                 };
-                try_opt!(f.return_type.validate_with(&mut *child.with_ctx(ctx)))
+                match &f.return_type {
+                    // A return type which cannot be validated is treated as `any`, so the
+                    // signature is still declared, which matters for overloads.
+                    Some(ret_ty) => {
+                        let res = ret_ty.validate_with(&mut *child.with_ctx(ctx));
+                        Some(res.unwrap_or_else(|err| {
+                            child.storage.report(err);
+
+                            Type::any(ret_ty.span, Default::default())
+                        }))
+                    }
+                    None => child.jsdoc_return_type_of_fn(f),
+                }
             }
             .freezed();
 
+            if let Some(ret_ty) = &declared_ret_ty {
+                child.validate_type_predicate_of_fn(&params, ret_ty);
+
+                if f.is_async && !f.is_generator {
+                    child.validate_return_type_of_async_fn(ret_ty);
+                }
+            }
+
             child.scope.declared_return_type = declared_ret_ty.clone();
+            if declared_ret_ty.is_none() {
+                child.scope.contextual_return_type = contextual_ret_ty.cloned().freezed();
+            }
 
             if let Some(ty) = &mut declared_ret_ty {
                 ty.freeze();
@@ -143,11 +300,49 @@ impl Analyzer<'_, '_> {
             }
 
             if let Some(ty) = &mut declared_ret_ty {
-                if let Type::Ref(..) = ty.normalize() {
+                if let Type::Ref(..) | Type::IndexedAccessType(..) | Type::Query(..) = ty.normalize() {
                     child.prevent_expansion(ty);
                 }
             }
 
+            if let Some(name) = name {
+                let id: Id = name.into();
+                // Recursive calls in the implementation of an overloaded function are resolved
+                // against the overload signatures. Otherwise, if the return type is known,
+                // recursive calls can use the declared signature instead of `typeof name`.
+                //
+                // Signatures of overloads don't depend on the body, so this can't recurse.
+                let fn_ty = match child.scope.overloads_of_declaring_fn(&id) {
+                    Some(overloads) => Some(overloads.clone()),
+                    None if child.scope.is_declaring_fn(&id) => declared_ret_ty.as_ref().map(|declared| {
+                        Type::Function(ty::Function {
+                            span: f.span,
+                            type_params: type_params.clone(),
+                            params: params.clone(),
+                            ret_ty: box declared.clone(),
+                            metadata: Default::default(),
+                            tracker: Default::default(),
+                        })
+                        .freezed()
+                    }),
+                    None => None,
+                };
+
+                if let Some(fn_ty) = fn_ty {
+                    child.scope.vars.insert(
+                        id,
+                        VarInfo {
+                            kind: VarKind::Fn,
+                            initialized: true,
+                            ty: Some(fn_ty.clone()),
+                            actual_ty: Some(fn_ty),
+                            copied: false,
+                            is_actual_type_modified_in_loop: false,
+                        },
+                    );
+                }
+            }
+
             let span = f.span;
             let is_async = f.is_async;
             let is_generator = f.is_generator;
@@ -181,8 +376,9 @@ impl Analyzer<'_, '_> {
                             }
                         }
 
-                        if child.may_generalize(&inferred_return_type) {
-                            inferred_return_type = inferred_return_type.generalize_lit();
+                        // Literals are preserved if the contextual return type may contain them.
+                        if !contextual_ret_ty.map_or(false, may_contain_lit) && child.may_generalize(&inferred_return_type) {
+                            inferred_return_type = child.widen_enum_variants(inferred_return_type.generalize_lit());
                         }
                     }
 
@@ -194,37 +390,24 @@ impl Analyzer<'_, '_> {
                     if let Some(ref declared) = declared_ret_ty {
                         span = declared.span();
                         let declared = child.normalize(Some(span), Cow::Borrowed(declared), Default::default())?;
-
-                        match declared.normalize() {
-                            Type::Keyword(KeywordType {
-                                kind: TsKeywordTypeKind::TsAnyKeyword,
-                                ..
-                            })
-                            | Type::Keyword(KeywordType {
-                                kind: TsKeywordTypeKind::TsVoidKeyword,
-                                ..
-                            })
-                            | Type::Keyword(KeywordType {
-                                kind: TsKeywordTypeKind::TsNeverKeyword,
-                                ..
-                            }) => {}
-                            _ => errors.push(ErrorKind::ReturnRequired { span }.into()),
+                        // `Promise<void>` does not require a return value.
+                        let declared = if f.is_async {
+                            child.get_awaited_type(span, declared)?
+                        } else {
+                            declared
+                        };
+
+                        if declared.is_never() {
+                            errors.push(ErrorKind::FnReturningNeverHasReachableEnd { span }.into())
+                        } else if !declared.iter_union().any(|ty| match ty.normalize() {
+                            Type::Predicate(p) => p.asserts,
+                            ty => ty.is_any() || ty.is_kwd(TsKeywordTypeKind::TsVoidKeyword),
+                        }) {
+                            errors.push(ErrorKind::ReturnRequired { span }.into())
                         }
                     }
 
                     // No return statement -> void
-                    if f.return_type.is_none() {
-                        if let Some(m) = &mut child.mutations {
-                            if m.for_fns.entry(f.node_id).or_default().ret_ty.is_none() {
-                                m.for_fns.entry(f.node_id).or_default().ret_ty = Some(Type::Keyword(KeywordType {
-                                    span,
-                                    kind: TsKeywordTypeKind::TsVoidKeyword,
-                                    metadata: Default::default(),
-                                    tracker: Default::default(),
-                                }));
-                            }
-                        }
-                    }
                     Type::Keyword(KeywordType {
                         span,
                         kind: TsKeywordTypeKind::TsVoidKeyword,
@@ -238,28 +421,270 @@ impl Analyzer<'_, '_> {
             inferred_return_type.freeze();
 
             if f.return_type.is_none() {
-                if let Some(m) = &mut child.mutations {
-                    if m.for_fns.entry(f.node_id).or_default().ret_ty.is_none() {
-                        m.for_fns.entry(f.node_id).or_default().ret_ty = Some(inferred_return_type.clone())
-                    }
-                }
+                // The type from JSDoc is used if any.
+                child.record_return_type_of_fn(f.span, f.node_id, declared_ret_ty.as_ref().unwrap_or(&inferred_return_type));
             }
 
+            // Errors of parameters and the body are not reported in source order.
+            errors.append_errors(&mut child.storage.take_errors());
+            errors.sort();
             child.storage.report_all(errors);
 
+            let mut metadata = FunctionMetadata::default();
+            if child.record_deprecation_of_fn(f) {
+                metadata.common.flags.insert(TypeFlags::DEPRECATED);
+            }
+
             Ok(ty::Function {
                 span: f.span,
                 type_params,
                 params,
                 ret_ty: box declared_ret_ty.unwrap_or(inferred_return_type),
-                metadata: Default::default(),
+                metadata,
                 tracker: Default::default(),
             })
-        })
+        })?;
+
+        if let Some(key) = cache_key {
+            self.data.fn_types.entry(f.node_id).or_default().insert(key, fn_ty.clone());
+        }
+
+        Ok(fn_ty)
     }
 }
 
 impl Analyzer<'_, '_> {
+    /// Validates `f` and checks if its type is assignable to `expected`.
+    ///
+    /// The function is validated in a new child scope, so identifiers used by
+    /// `f` and `expected` should be resolvable from the current scope. Use an
+    /// analyzer which already visited the module containing the referenced
+    /// declarations, or [Analyzer::root] for signatures only using builtin
+    /// types.
+    pub fn check_function_type(&mut self, f: &RFunction, expected: &ty::Function) -> VResult<()> {
+        let actual = self.with_child(ScopeKind::Fn, Default::default(), |a: &mut Analyzer| {
+            f.validate_with_args(a, (None, Some(&*expected.ret_ty)))
+        })?;
+
+        self.assign_with_opts(
+            &mut Default::default(),
+            &Type::Function(expected.clone()).freezed(),
+            &Type::Function(actual).freezed(),
+            AssignOpts {
+                span: f.span,
+                ..Default::default()
+            },
+        )
+        .context("tried to check the type of a function")
+    }
+
+    /// Validates the type predicate used as the return type of a function.
+    ///
+    /// ```ts
+    /// function isString(x: unknown): x is string;
+    /// ```
+    fn validate_type_predicate_of_fn(&mut self, params: &[FnParam], ret_ty: &Type) {
+        if self.config.is_builtin {
+            return;
+        }
+
+        let predicate = match ret_ty.normalize() {
+            Type::Predicate(p) => p,
+            _ => return,
+        };
+        let name = match &predicate.param_name {
+            RTsThisTypeOrIdent::Ident(name) => name,
+            RTsThisTypeOrIdent::TsThisType(..) => return,
+        };
+
+        let param = params.iter().find(|param| match &param.pat {
+            RPat::Ident(i) => i.id.sym != js_word!("this") && i.id.sym == name.sym,
+            _ => false,
+        });
+        let param = match param {
+            Some(param) => param,
+            None => {
+                self.storage
+                    .report(ErrorKind::TypePredicateParamNotFound { span: name.span }.into());
+                return;
+            }
+        };
+
+        if let Some(ty) = &predicate.ty {
+            if let Err(err) = self.assign_with_opts(
+                &mut Default::default(),
+                &param.ty,
+                ty,
+                AssignOpts {
+                    span: predicate.span,
+                    ..Default::default()
+                },
+            ) {
+                self.storage.report(
+                    ErrorKind::TypePredicateNotAssignableToParam {
+                        span: ty.span(),
+                        cause: box err,
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+
+    /// Returns the signatures of overloads declared with `id`, if no
+    /// implementation is declared yet.
+    ///
+    /// ```ts
+    /// function f(a: string): string;
+    /// function f(a: number): number;
+    /// function f(a: any) {}
+    /// ```
+    fn overload_signatures(&self, id: &Id) -> Option<Type> {
+        if self.data.fn_impl_spans.get(id).map_or(false, |spans| !spans.is_empty()) {
+            return None;
+        }
+
+        let v = self.scope.get_var(id)?;
+        if v.kind != VarKind::Fn {
+            return None;
+        }
+
+        let ty = v.ty.as_ref()?;
+        if !ty.iter_union().all(|ty| matches!(ty.normalize(), Type::Function(..))) {
+            return None;
+        }
+
+        Some(ty.clone())
+    }
+
+    /// Reports overload signatures which cannot be selected because an earlier
+    /// overload signature accepts all arguments of them.
+    ///
+    /// ```ts
+    /// function f(a: string | number): string;
+    /// function f(a: string): string; // Unreachable
+    /// function f(a: any) {}
+    /// ```
+    fn report_unreachable_overloads(&mut self, overloads: &Type) {
+        if !self.rule().report_unreachable_overloads || self.config.is_builtin {
+            return;
+        }
+
+        let signatures = overloads
+            .iter_union()
+            .filter_map(|ty| match ty.normalize() {
+                Type::Function(f) => Some(f),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        for (idx, later) in signatures.iter().enumerate() {
+            if let Some(earlier) = signatures[..idx].iter().find(|earlier| self.accepts_all_args_of(earlier, later)) {
+                self.storage.report(
+                    ErrorKind::UnreachableOverload {
+                        span: later.span,
+                        shadowed_by: earlier.span,
+                    }
+                    .into(),
+                );
+            }
+        }
+    }
+
+    /// Returns `true` if all argument lists accepted by `r` are accepted by
+    /// `l`.
+    fn accepts_all_args_of(&mut self, l: &Function, r: &Function) -> bool {
+        // TODO: Compare generic signatures and rest parameters.
+        if l.type_params.is_some() || r.type_params.is_some() {
+            return false;
+        }
+        if l.params.iter().chain(r.params.iter()).any(|p| matches!(p.pat, RPat::Rest(..))) {
+            return false;
+        }
+
+        let l_required = l.params.iter().filter(|p| p.required).count();
+        let r_required = r.params.iter().filter(|p| p.required).count();
+        if l_required > r_required || l.params.len() < r.params.len() {
+            return false;
+        }
+
+        l.params
+            .iter()
+            .zip(r.params.iter())
+            .all(|(l, r)| self.assign(r.span, &mut Default::default(), &l.ty, &r.ty).is_ok())
+    }
+
+    /// Reports type parameters which are not referenced by the parameters,
+    /// the return type, the body or constraints of other type parameters.
+    fn report_unused_type_params(&mut self, f: &RFunction) {
+        let decl = match &f.type_params {
+            Some(v) => v,
+            None => return,
+        };
+        if !self.rule().no_unused_parameters || self.ctx.in_declare || f.body.is_none() {
+            return;
+        }
+
+        let mut v = TypeRefFinder::default();
+        f.visit_with(&mut v);
+
+        let unused = decl
+            .params
+            .iter()
+            .filter(|p| !v.found.contains(&Id::from(&p.name)))
+            .collect::<Vec<_>>();
+
+        if decl.params.len() >= 2 && unused.len() == decl.params.len() {
+            self.storage.report(ErrorKind::AllTypeParamsUnused { span: decl.span }.into());
+            return;
+        }
+
+        for p in unused {
+            self.storage.report(ErrorKind::UnusedTypeParam { span: p.name.span }.into());
+        }
+    }
+
+    /// Returns true if types of parameters of `f` are inferred from a
+    /// contextual type.
+    fn has_contextual_param_types(&self, f: &RFunction) -> bool {
+        let m = match &self.mutations {
+            Some(m) => m,
+            None => return false,
+        };
+
+        f.params
+            .iter()
+            .filter_map(|p| p.pat.node_id())
+            .any(|id| m.for_pats.get(&id).map_or(false, |v| v.ty.is_some()))
+    }
+
+    /// A parameter with a default value is optional only if all parameters
+    /// following it are optional.
+    ///
+    /// ```ts
+    /// function f(a = 1, b: string) {}
+    /// f(undefined, '') // `a` is required, but it accepts `undefined`.
+    /// ```
+    fn mark_params_with_default_followed_by_required_as_required(&mut self, params: &mut [FnParam]) {
+        let mut has_required_after = false;
+        for param in params.iter_mut().rev() {
+            if param.required {
+                has_required_after = true;
+                continue;
+            }
+
+            if !has_required_after || !matches!(param.pat, RPat::Assign(..)) {
+                continue;
+            }
+
+            param.required = true;
+            if self.rule().strict_null_checks {
+                let span = param.span;
+                param.ty = box Type::union(vec![*param.ty.clone(), Type::undefined(span, Default::default())]).freezed();
+            }
+        }
+    }
+
     pub(crate) fn fn_to_type_element(&mut self, f: &Function) -> VResult<TypeElement> {
         Ok(TypeElement::Call(CallSignature {
             span: f.span.with_ctxt(SyntaxContext::empty()),
@@ -273,7 +698,7 @@ impl Analyzer<'_, '_> {
     ///
     /// If the referred type has default type parameter, we have to include it
     /// in function type of output (.d.ts)
-    fn qualify_ref_type_args(&mut self, span: Span, mut ty: Ref) -> VResult<Ref> {
+    pub(super) fn qualify_ref_type_args(&mut self, span: Span, mut ty: Ref) -> VResult<Ref> {
         let actual_ty = self.type_of_ts_entity_name(span, &ty.type_name.clone().into(), ty.type_args.as_deref())?;
 
         // TODO(kdy1): PERF
@@ -304,68 +729,84 @@ impl Analyzer<'_, '_> {
 
         self.prevent_expansion(&mut ty);
 
-        if let Some(args) = ty.type_args.as_mut() {
-            for (span, default) in type_params
-                .params
-                .into_iter()
-                .skip(arg_cnt)
-                .map(|param| (param.span, param.default.map(|v| *v)))
-            {
-                if let Some(default) = default {
-                    args.params.push(default);
+        // Missing type arguments without a default value are reported once for the
+        // reference, like tsc.
+        let min = type_params.params.iter().filter(|param| param.default.is_none()).count();
+        let max = type_params.params.len();
+        if arg_cnt < min {
+            self.storage.report(
+                if min == max {
+                    ErrorKind::GenericTypeRequiresTypeArgs { span, count: max }
                 } else {
-                    self.storage
-                        .report(ErrorKind::ImplicitAny { span }.context("qualify_ref_type_args"));
-                    args.params
-                        .push(Type::any(span.with_ctxt(SyntaxContext::empty()), Default::default()));
+                    ErrorKind::GenericTypeRequiresTypeArgsInRange { span, min, max }
                 }
-            }
+                .context("qualify_ref_type_args"),
+            );
+        }
+
+        let args = ty.type_args.get_or_insert_with(|| box TypeParamInstantiation {
+            span,
+            params: Default::default(),
+        });
+        for (span, default) in type_params
+            .params
+            .into_iter()
+            .skip(arg_cnt)
+            .map(|param| (param.span, param.default.map(|v| *v)))
+        {
+            args.params
+                .push(default.unwrap_or_else(|| Type::any(span.with_ctxt(SyntaxContext::empty()), Default::default())));
         }
 
         Ok(ty)
     }
 
-    /// TODO(kdy1): Handle recursive function
+    /// While validating the body, references to `name` are resolved as
+    /// `typeof name`, unless the return type is annotated.
     fn visit_fn(&mut self, name: Option<&RIdent>, f: &RFunction, type_ann: Option<&Type>) -> Type {
+        // Named functions can be nested, so we restore the previous value after
+        // validation.
+        let prev_declaring_fn = name.map(|name| {
+            let id: Id = name.into();
+            let overloads = if f.body.is_some() { self.overload_signatures(&id) } else { None };
+            self.scope.declaring_fn.replace((id, overloads))
+        });
+
         let fn_ty: Result<_, _> = try {
-            let no_implicit_any_span = name.as_ref().map(|name| name.span);
+            let mut a = self.with_contextual_type(type_ann.cloned());
+            let type_ann = a.contextual_type().cloned();
 
-            self.apply_fn_type_ann(f.span, f.params.iter().map(|p| &p.pat), type_ann);
-
-            // if let Some(name) = name {
-            //     // We use `typeof function` to infer recursive function's return type.
-            //     match self.declare_var(
-            //         f.span,
-            //         VarDeclKind::Var,
-            //         name.into(),
-            //         Some(Type::Query(QueryType {
-            //             span: f.span,
-            //             expr: RTsEntityName::Ident(name.clone()).into(),
-            //         })),
-            //         // value is initialized
-            //         true,
-            //         // Allow overriding
-            //         true,
-            //     ) {
-            //         Ok(()) => {}
-            //         Err(err) => {
-            //             self.storage.report(err);
-            //         }
-            //     }
-            // }
+            let no_implicit_any_span = name.as_ref().map(|name| name.span);
 
-            if let Some(name) = name {
-                assert_eq!(self.scope.declaring_fn, None);
-                self.scope.declaring_fn = Some(name.into());
-            }
+            a.apply_fn_type_ann(f.span, f.params.iter().map(|p| &p.pat), type_ann.as_ref());
+            let contextual_ret_ty = if f.return_type.is_none() {
+                a.get_return_type_from_fn_type_ann(f.span, type_ann.as_ref())
+            } else {
+                None
+            };
 
-            let mut fn_ty: ty::Function = f.validate_with_args(self, name)?;
+            let mut fn_ty: ty::Function = f.validate_with_args(&mut *a, (name, contextual_ret_ty.as_ref()))?;
             // Handle type parameters in return type.
             fn_ty.ret_ty = fn_ty.ret_ty.fold_with(&mut TypeParamHandler {
                 params: fn_ty.type_params.as_ref().map(|v| &*v.params),
             });
             let ty::Function { ref mut ret_ty, .. } = fn_ty;
-            if let Type::Tuple(Tuple { ref mut elems, .. }) = **ret_ty {
+            // Tuples from const assertions and annotated return types keep their element
+            // types.
+            if let (
+                None,
+                Type::Tuple(Tuple {
+                    elems,
+                    metadata:
+                        TupleMetadata {
+                            prevent_tuple_to_array: false,
+                            ..
+                        },
+                    ..
+                }),
+            ) = (&f.return_type, &mut **ret_ty)
+            {
+                let mut reported_implicit_any = false;
                 for element in elems.iter_mut() {
                     let span = element.span();
 
@@ -381,13 +822,16 @@ impl Analyzer<'_, '_> {
                         _ => continue,
                     }
 
-                    //if child.rule.no_implicit_any
-                    //    && child.span_allowed_implicit_any != f.span
-                    //{
-                    //    child.storage.report(Error::ImplicitAny {
-                    //        span: no_implicit_any_span.unwrap_or(span),
-                    //    });
-                    //}
+                    if a.rule().no_implicit_any && !reported_implicit_any {
+                        a.storage.report(
+                            ErrorKind::ImplicitAny {
+                                span: no_implicit_any_span.unwrap_or(span),
+                            }
+                            .context("tuple type widening"),
+                        );
+                        // The name of the function is reported only once.
+                        reported_implicit_any = no_implicit_any_span.is_some();
+                    }
 
                     element.ty = box Type::any(
                         span,
@@ -399,13 +843,13 @@ impl Analyzer<'_, '_> {
                 }
             };
 
-            if let Some(name) = name {
-                self.scope.declaring_fn = None;
-            }
-
             fn_ty
         };
 
+        if let Some(prev) = prev_declaring_fn {
+            self.scope.declaring_fn = prev;
+        }
+
         match fn_ty {
             Ok(ty) => Type::Function(ty).fixed().freezed(),
             Err(err) => {
@@ -434,8 +878,16 @@ impl Analyzer<'_, '_> {
             in_declare: self.ctx.in_declare || f.declare || f.function.body.is_none(),
             in_async: f.function.is_async,
             in_generator: f.function.is_generator,
+            in_fn_expr: false,
             ..self.ctx
         };
+        let id: Id = f.ident.clone().into();
+        let overloads = if f.function.body.is_some() {
+            self.overload_signatures(&id)
+        } else {
+            None
+        };
+
         let fn_ty = self
             .with_ctx(ctx)
             .with_child(ScopeKind::Fn, Default::default(), |a: &mut Analyzer| {
@@ -443,13 +895,36 @@ impl Analyzer<'_, '_> {
             })?;
 
         let mut a = self.with_ctx(ctx);
-        match a.declare_var(f.span(), VarKind::Fn, f.ident.clone().into(), Some(fn_ty), None, true, true, false) {
+
+        // Overloads declared with JSDoc tags are validated like overloads declared in
+        // TypeScript.
+        let overloads = match overloads {
+            Some(v) => Some(v),
+            None if f.function.body.is_some() => a.jsdoc_overloads_of_fn(&f.function).map(|overloads| {
+                a.validate_fn_overloads(f.span(), &overloads, &fn_ty).report(&mut a.storage);
+                overloads
+            }),
+            None => None,
+        };
+        if let Some(overloads) = &overloads {
+            a.report_unreachable_overloads(overloads);
+        }
+
+        match a.declare_var(f.span(), VarKind::Fn, id.clone(), Some(fn_ty), None, true, true, false) {
             Ok(..) => {}
             Err(err) => {
                 a.storage.report(err);
             }
         }
 
+        // The signature of the implementation is not visible from outside.
+        if let Some(overloads) = overloads {
+            if let Some(v) = a.scope.vars.get_mut(&id) {
+                v.ty = Some(overloads.clone());
+                v.actual_ty = Some(overloads);
+            }
+        }
+
         Ok(())
     }
 }
@@ -458,7 +933,28 @@ impl Analyzer<'_, '_> {
 impl Analyzer<'_, '_> {
     /// NOTE: This method **should not call f.fold_children_with(self)**
     fn validate(&mut self, f: &RFnExpr, type_ann: Option<&Type>) -> VResult<Type> {
-        Ok(self.visit_fn(f.ident.as_ref(), &f.function, type_ann))
+        let ctx = Ctx {
+            in_fn_expr: true,
+            ..self.ctx
+        };
+        Ok(self.with_ctx(ctx).visit_fn(f.ident.as_ref(), &f.function, type_ann))
+    }
+}
+
+/// Collects names used as types.
+#[derive(Default)]
+struct TypeRefFinder {
+    found: FxHashSet<Id>,
+}
+
+impl Visit<RTsEntityName> for TypeRefFinder {
+    fn visit(&mut self, n: &RTsEntityName) {
+        match n {
+            RTsEntityName::Ident(i) => {
+                self.found.insert(i.into());
+            }
+            RTsEntityName::TsQualifiedName(..) => n.visit_children_with(self),
+        }
     }
 }
 
@@ -495,3 +991,42 @@ impl Fold<Type> for TypeParamHandler<'_> {
         }
     }
 }
+
+/// Returns true if `ty` may contain literal types, like `"a" | "b"` or
+/// `{ kind: "a" }`.
+///
+/// References and type parameters are not resolved, so they may contain
+/// literal types.
+fn may_contain_lit(ty: &Type) -> bool {
+    let mut v = LitFinder { found: false };
+    ty.visit_with(&mut v);
+    v.found
+}
+
+struct LitFinder {
+    found: bool,
+}
+
+impl Visit<LitType> for LitFinder {
+    fn visit(&mut self, _: &LitType) {
+        self.found = true;
+    }
+}
+
+impl Visit<EnumVariant> for LitFinder {
+    fn visit(&mut self, _: &EnumVariant) {
+        self.found = true;
+    }
+}
+
+impl Visit<TypeParam> for LitFinder {
+    fn visit(&mut self, _: &TypeParam) {
+        self.found = true;
+    }
+}
+
+impl Visit<Ref> for LitFinder {
+    fn visit(&mut self, _: &Ref) {
+        self.found = true;
+    }
+}
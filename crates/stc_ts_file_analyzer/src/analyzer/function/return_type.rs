@@ -1,13 +1,140 @@
-use rnode::{VisitMut, VisitMutWith};
-use stc_ts_ast_rnode::RTsEntityName;
+use std::borrow::Cow;
+
+use rnode::{NodeId, NodeIdGenerator, RNode, VisitMut, VisitMutWith};
+use stc_ts_ast_rnode::{RFunction, RIdent, RTsEntityName, RTsType};
+use stc_ts_errors::ErrorKind;
 use stc_ts_types::{QueryExpr, QueryType, Type};
+use stc_ts_utils::jsdoc::{find_return_type_in_jsdoc, JsDocReturnType};
+use stc_utils::cache::Freeze;
+use swc_common::{BytePos, Span, Spanned, SyntaxContext};
+use swc_ecma_ast::{Decl, EsVersion, ModuleItem, Stmt, TsType};
+use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
+use tracing::warn;
 
 use crate::{
-    analyzer::{scope::VarInfo, Analyzer},
+    analyzer::{
+        scope::{ExpandOpts, VarInfo},
+        util::ResultExt,
+        Analyzer, ScopeKind,
+    },
+    util::unwrap_ref_with_single_arg,
+    validator::ValidateWith,
     VResult,
 };
 
 impl Analyzer<'_, '_> {
+    /// Records the return type of a function-like node without a return type
+    /// annotation, so it can be used while generating `.d.ts` files.
+    ///
+    /// The type is expanded fully, because it may reference types which are
+    /// not accessible from the declaration file.
+    ///
+    /// This is the only place which records return types of functions, and
+    /// the first recorded type wins.
+    pub(crate) fn record_return_type_of_fn(&mut self, span: Span, node_id: NodeId, ret_ty: &Type) {
+        match &self.mutations {
+            Some(m) if m.for_fns.get(&node_id).map_or(true, |v| v.ret_ty.is_none()) => {}
+            _ => return,
+        }
+
+        let ret_ty = self
+            .expand(
+                span,
+                ret_ty.clone(),
+                ExpandOpts {
+                    full: true,
+                    expand_union: false,
+                    ..Default::default()
+                },
+            )
+            .unwrap_or_else(|_| ret_ty.clone())
+            .freezed();
+
+        if let Some(m) = &mut self.mutations {
+            m.for_fns.entry(node_id).or_default().ret_ty = Some(ret_ty);
+        }
+        // A cached type of the function may not match the recorded return type.
+        self.data.fn_types.remove(&node_id);
+    }
+
+    /// Returns the type of `@returns` JSDoc tag of `f`.
+    ///
+    /// Malformed tags and types which cannot be resolved are ignored, so the
+    /// caller should fall back to inference.
+    pub(super) fn jsdoc_return_type_of_fn(&mut self, f: &RFunction) -> Option<Type> {
+        if self.config.is_builtin || !self.rule().use_jsdoc_types {
+            return None;
+        }
+
+        let (text, pos) = match find_return_type_in_jsdoc(&self.comments, f.span.lo)? {
+            JsDocReturnType::Type { text, pos } => (text, pos),
+            JsDocReturnType::Malformed { span } => {
+                warn!("Ignoring malformed `@returns` tag at {:?}", span);
+                return None;
+            }
+        };
+
+        self.type_from_jsdoc("@returns", &text, pos).map(|(_, ty)| ty)
+    }
+
+    /// Parses and validates the type expression `text` of a JSDoc tag.
+    ///
+    /// Returns [None] if the type is invalid or cannot be resolved.
+    pub(super) fn type_from_jsdoc(&mut self, tag: &str, text: &str, pos: BytePos) -> Option<(RTsType, Type)> {
+        let ty = match parse_jsdoc_type(text, pos) {
+            Some(ty) => ty,
+            None => {
+                warn!("Ignoring `{}` tag with an invalid type: {}", tag, text);
+                return None;
+            }
+        };
+        let mut ty = RTsType::from_orig(&mut NodeIdGenerator::invalid(), ty);
+        ty.visit_mut_with(&mut CtxtApplier {
+            ctxt: self.storage.top_level_ctxt(self.ctx.module_id),
+        });
+
+        self.with_child(ScopeKind::Flow, Default::default(), |child: &mut Analyzer| -> VResult<_> {
+            let validated = ty.validate_with(child)?;
+
+            // The type is not resolvable if something is reported.
+            if !child.storage.take_errors().is_empty() {
+                warn!("Ignoring `{}` tag with an unresolvable type: {}", tag, text);
+                return Ok(None);
+            }
+
+            Ok(Some((ty, validated)))
+        })
+        .report(&mut self.storage)
+        .flatten()
+    }
+
+    /// With es2015 or later, the declared return type of an async function
+    /// should be the global `Promise<T>`, and other thenables are not allowed.
+    pub(crate) fn validate_return_type_of_async_fn(&mut self, ret_ty: &Type) {
+        if self.env.target() < EsVersion::Es2015 || ret_ty.is_any() {
+            return;
+        }
+
+        if unwrap_ref_with_single_arg(ret_ty, "Promise").is_some() {
+            return;
+        }
+
+        let is_promise = match self.normalize(Some(ret_ty.span()), Cow::Borrowed(ret_ty), Default::default()) {
+            Ok(ty) => match ty.normalize() {
+                Type::Interface(i) => i.name == "Promise",
+                // Failed to resolve the type, so the error is already reported.
+                Type::Ref(..) => true,
+                _ => false,
+            },
+            Err(..) => true,
+        };
+
+        if !is_promise {
+            self.storage
+                .report(ErrorKind::ReturnTypeOfAsyncFnNotPromise { span: ret_ty.span() }.into());
+        }
+    }
+
     pub(crate) fn expand_return_type_of_fn(&mut self, ret_ty: &mut Type) -> VResult<()> {
         if self.config.is_builtin {
             return Ok(());
@@ -51,3 +178,41 @@ impl VisitMut<Type> for FnReturnTypeHandler<'_, '_, '_> {
         }
     }
 }
+
+/// Parses `text` as a type, using `pos` as the start position of spans.
+fn parse_jsdoc_type(text: &str, pos: BytePos) -> Option<TsType> {
+    const PREFIX: &str = "type T = ";
+
+    let src = format!("{}{};", PREFIX, text);
+    let lo = BytePos(pos.0.checked_sub(PREFIX.len() as u32)?);
+    let hi = lo + BytePos(src.len() as u32);
+
+    let lexer = Lexer::new(
+        Syntax::Typescript(TsConfig { ..Default::default() }),
+        EsVersion::latest(),
+        StringInput::new(&src, lo, hi),
+        None,
+    );
+    let mut parser = Parser::new_from(lexer);
+    let module = parser.parse_module().ok()?;
+    if !parser.take_errors().is_empty() {
+        return None;
+    }
+
+    match module.body.into_iter().next()? {
+        ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => Some(*alias.type_ann),
+        _ => None,
+    }
+}
+
+/// Makes identifiers in a type parsed from a comment refer to the top-level
+/// scope.
+struct CtxtApplier {
+    ctxt: SyntaxContext,
+}
+
+impl VisitMut<RIdent> for CtxtApplier {
+    fn visit_mut(&mut self, i: &mut RIdent) {
+        i.span.ctxt = self.ctxt;
+    }
+}
@@ -4,7 +4,7 @@ use stc_ts_env::Env;
 use stc_ts_errors::debug::dump_type_as_string;
 use stc_ts_type_ops::{is_str_lit_or_union, PreventComplexSimplification};
 use stc_ts_types::{
-    Array, Class, ClassDef, ClassMember, CommonTypeMetadata, IndexedAccessType, IndexedAccessTypeMetadata, Key, KeywordType,
+    Array, Class, ClassDef, ClassMember, CommonTypeMetadata, EnumVariant, IndexedAccessType, IndexedAccessTypeMetadata, Key, KeywordType,
     KeywordTypeMetadata, LitType, LitTypeMetadata, Mapped, Operator, PropertySignature, TypeElement, TypeLit, TypeLitMetadata, TypeParam,
     Union,
 };
@@ -46,6 +46,24 @@ impl Analyzer<'_, '_> {
         !ty.metadata().prevent_generalization
     }
 
+    /// Widens members of enums to the enum, like `E.A` to `E`.
+    ///
+    /// This is applied to inferred return types, just like literals are
+    /// generalized.
+    pub(super) fn widen_enum_variants(&self, ty: Type) -> Type {
+        match ty.normalize() {
+            Type::EnumVariant(v @ EnumVariant { name: Some(..), .. }) if !v.metadata.common.prevent_generalization => {
+                Type::EnumVariant(EnumVariant { name: None, ..v.clone() })
+            }
+            Type::Union(u) if u.types.iter().any(|ty| matches!(ty.normalize(), Type::EnumVariant(..))) => {
+                let types = u.types.iter().map(|ty| self.widen_enum_variants(ty.clone())).collect::<Vec<_>>();
+
+                Type::new_union(u.span, types)
+            }
+            _ => ty,
+        }
+    }
+
     #[cfg_attr(debug_assertions, tracing::instrument(skip_all))]
     pub(super) fn prevent_inference_while_simplifying(&self, ty: &mut Type) {
         ty.visit_mut_with(&mut PreventComplexSimplification);
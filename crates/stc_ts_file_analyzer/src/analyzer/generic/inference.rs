@@ -12,7 +12,7 @@ use itertools::Itertools;
 use stc_ts_ast_rnode::{RStr, RTsEntityName, RTsLit};
 use stc_ts_errors::{debug::dump_type_as_string, DebugExt};
 use stc_ts_generics::expander::InferTypeResult;
-use stc_ts_type_ops::generalization::prevent_generalize;
+use stc_ts_type_ops::{generalization::prevent_generalize, tuple_to_array::prevent_tuple_to_array};
 use stc_ts_types::{
     Array, ArrayMetadata, Class, ClassDef, ClassMember, Function, Id, Interface, KeywordType, KeywordTypeMetadata, LitType, Operator, Ref,
     TplElem, TplType, Tuple, TupleElement, Type, TypeElement, TypeLit, TypeParam, TypeParamMetadata, Union,
@@ -1444,6 +1444,15 @@ impl Analyzer<'_, '_> {
         is_from_type_ann: bool,
     ) {
         for type_param in type_params {
+            // Types inferred for `const T` are not widened, like `[1, "a"] as const`.
+            if type_param.metadata.is_const() {
+                if let Some(ty) = inferred.type_params.get_mut(&type_param.name) {
+                    prevent_generalize(&mut ty.inferred_type);
+                    prevent_tuple_to_array(&mut ty.inferred_type);
+                }
+                continue;
+            }
+
             if !inferred.skip_generalization {
                 match type_param.constraint.as_deref() {
                     Some(Type::Lit(..)) => {}
@@ -15,8 +15,8 @@ use stc_ts_generics::{
 use stc_ts_type_ops::{generalization::prevent_generalize, Fix};
 use stc_ts_types::{
     replace::replace_type, Array, ClassMember, FnParam, Function, Id, IdCtx, IndexSignature, IndexedAccessType, Intersection, Key,
-    KeywordType, KeywordTypeMetadata, LitType, LitTypeMetadata, Mapped, Operator, OptionalType, PropertySignature, Ref, Tuple,
-    TupleElement, TupleMetadata, Type, TypeElement, TypeLit, TypeOrSpread, TypeParam, TypeParamDecl, TypeParamInstantiation,
+    KeywordType, KeywordTypeMetadata, LitType, LitTypeMetadata, Mapped, Operator, OptionalType, PropertySignature, Ref, RestType, Tuple,
+    TupleElement, TupleMetadata, Type, TypeElement, TypeFlags, TypeLit, TypeOrSpread, TypeParam, TypeParamDecl, TypeParamInstantiation,
     TypeParamMetadata, Union, UnionMetadata,
 };
 use stc_ts_utils::MapWithMut;
@@ -487,6 +487,10 @@ impl Analyzer<'_, '_> {
 
         debug_assert!(!span.is_dummy(), "infer_type: `span` should not be dummy");
 
+        if param.metadata().flags.contains(TypeFlags::NO_INFER) {
+            return Ok(());
+        }
+
         if param.is_keyword() || param.type_eq(arg) {
             return Ok(());
         }
@@ -2146,7 +2150,25 @@ impl Analyzer<'_, '_> {
         args: &[FnParam],
         opts: InferTypeOpts,
     ) -> VResult<()> {
-        for (param, arg) in params.iter().zip(args) {
+        for (idx, (param, arg)) in params.iter().zip(args).enumerate() {
+            // `...args: infer P` receives the remaining parameters as a tuple, like
+            // `Parameters<T>`.
+            if let (RPat::Rest(..), Type::Infer(..) | Type::Param(..)) = (&param.pat, param.ty.normalize()) {
+                if !matches!(arg.pat, RPat::Rest(..)) {
+                    let tuple = params_to_tuple(span, &args[idx..]);
+                    return self.infer_type(
+                        span,
+                        inferred,
+                        &param.ty,
+                        &tuple,
+                        InferTypeOpts {
+                            append_type_as_union: opts.append_type_as_union || opts.for_fn_assignment,
+                            ..opts
+                        },
+                    );
+                }
+            }
+
             self.infer_type_of_fn_param(span, inferred, param, arg, opts)?
         }
 
@@ -2519,3 +2541,48 @@ fn handle_optional_for_element(element_ty: &mut Type, optional: Option<TruePlusM
         TruePlusMinus::Minus => {}
     }
 }
+
+/// Converts parameters to a labeled tuple. Optional parameters become optional
+/// elements and a rest parameter becomes a rest element.
+fn params_to_tuple(span: Span, params: &[FnParam]) -> Type {
+    let elems = params
+        .iter()
+        .map(|param| {
+            let (label, ty) = match &param.pat {
+                RPat::Rest(rest) => (
+                    (*rest.arg).clone(),
+                    Type::Rest(RestType {
+                        span: param.span,
+                        ty: param.ty.clone(),
+                        metadata: Default::default(),
+                        tracker: Default::default(),
+                    }),
+                ),
+                pat if !param.required => (
+                    pat.clone(),
+                    Type::Optional(OptionalType {
+                        span: param.span,
+                        ty: param.ty.clone(),
+                        metadata: Default::default(),
+                        tracker: Default::default(),
+                    }),
+                ),
+                pat => (pat.clone(), (*param.ty).clone()),
+            };
+
+            TupleElement {
+                span: param.span,
+                label: Some(label),
+                ty: box ty,
+                tracker: Default::default(),
+            }
+        })
+        .collect();
+
+    Type::Tuple(Tuple {
+        span,
+        elems,
+        metadata: Default::default(),
+        tracker: Default::default(),
+    })
+}
@@ -8,7 +8,7 @@ use std::{
 };
 
 use fxhash::{FxHashMap, FxHashSet};
-use rnode::VisitWith;
+use rnode::{NodeId, VisitWith};
 use stc_ts_ast_rnode::{
     RDecorator, RModule, RModuleDecl, RModuleItem, RScript, RStmt, RStr, RTsImportEqualsDecl, RTsModuleBlock, RTsModuleDecl, RTsModuleName,
     RTsModuleRef, RTsNamespaceDecl,
@@ -19,15 +19,17 @@ use stc_ts_env::{Env, Marks, ModuleConfig, Rule, StableEnv};
 use stc_ts_errors::{debug::debugger::Debugger, DebugExt, ErrorKind};
 use stc_ts_storage::{Builtin, Info, Storage};
 use stc_ts_type_cache::TypeCache;
-use stc_ts_types::{type_id::DestructureId, Id, IdCtx, ModuleId, ModuleTypeData, Namespace};
+use stc_ts_types::{type_id::DestructureId, Function, Id, IdCtx, ModuleId, ModuleTypeData, Namespace};
 use stc_ts_utils::StcComments;
 use stc_utils::{cache::Freeze, AHashMap, AHashSet};
 use swc_atoms::{js_word, JsWord};
 use swc_common::{FileName, SourceMap, Span, DUMMY_SP, GLOBALS};
 use swc_ecma_ast::*;
 
+pub use self::class::DesignType;
 use self::{
     control_flow::{CondFacts, Facts},
+    function::FnCacheKey,
     pat::PatMode,
     props::ComputedPropMode,
     scope::{Scope, VarKind},
@@ -157,6 +159,11 @@ pub(crate) struct Ctx {
     allow_ref_declaring: bool,
     in_argument: bool,
     in_fn_with_return_type: bool,
+    /// `true` while validating a function expression. Cleared for the body.
+    ///
+    /// Only function expressions and arrow functions can have `never` as the
+    /// inferred return type.
+    in_fn_expr: bool,
     in_return_arg: bool,
     in_assign_rhs: bool,
 
@@ -294,9 +301,47 @@ struct AnalyzerData {
 
     cache: TypeCache,
 
+    /// Types of functions validated without a contextual type, keyed by the
+    /// node id of `RFunction`.
+    fn_types: FxHashMap<NodeId, FxHashMap<FnCacheKey, Function>>,
+
+    /// Variables declared without a type annotation.
+    ///
+    /// Used to check if the callee of an assertion is explicitly typed.
+    vars_without_type_ann: FxHashSet<Id>,
+
+    /// `design:paramtypes` metadata of decorated methods, keyed by the node id
+    /// of `RFunction`.
+    design_param_types: FxHashMap<NodeId, Vec<DesignType>>,
+
+    /// Messages of `@deprecated` tags of functions, keyed by the span of
+    /// `RFunction`.
+    deprecation_messages: FxHashMap<Span, String>,
+
+    /// Spans of statements which follow a statement that cannot complete
+    /// normally, like `return` or `throw`.
+    ///
+    /// Filled by [Analyzer::mark_unreachable_stmts] and reported while
+    /// validating the statement.
+    unreachable_stmts: FxHashSet<Span>,
+
     checked_for_async_iterator: bool,
+
+    /// Contextual types of the functions being validated, pushed by
+    /// [Analyzer::with_contextual_type].
+    contextual_types: Vec<Option<Type>>,
+
+    /// Number of type references being expanded.
+    expand_depth: usize,
+
+    /// See [Analyzer::set_max_expand_depth].
+    max_expand_depth: Option<usize>,
 }
 
+/// Default value of [Analyzer::set_max_expand_depth], which is the
+/// instantiation depth limit of tsc.
+pub const DEFAULT_MAX_EXPAND_DEPTH: usize = 100;
+
 /// Configuration for the analyzer.
 #[derive(Debug, Default)]
 pub(crate) struct InnerConfig {
@@ -511,6 +556,7 @@ impl<'scope, 'b> Analyzer<'scope, 'b> {
                 allow_ref_declaring: false,
                 in_argument: false,
                 in_fn_with_return_type: false,
+                in_fn_expr: false,
                 in_return_arg: false,
                 in_assign_rhs: false,
                 in_export_decl: false,
@@ -675,6 +721,34 @@ impl<'scope, 'b> Analyzer<'scope, 'b> {
         WithCtx { analyzer: self, orig_ctx }
     }
 
+    /// Sets the maximum number of type references expanded at once, like
+    /// references to a recursive type alias in a function signature.
+    ///
+    /// Deeper references are replaced with `any`, and
+    /// [ErrorKind::InstantiationExcessivelyDeep] is reported instead of
+    /// overflowing the stack. Defaults to [DEFAULT_MAX_EXPAND_DEPTH].
+    pub fn set_max_expand_depth(&mut self, depth: usize) {
+        self.data.max_expand_depth = Some(depth);
+    }
+
+    /// Pushes `ty` as the contextual type of the function being validated, and
+    /// pops it when the returned guard is dropped.
+    ///
+    /// [None] is also pushed, so a function without a contextual type does not
+    /// see the contextual type of the enclosing function. Unlike [Ctx], which
+    /// is replaced as a whole by [Analyzer::with_ctx], this is a stack and
+    /// is not affected by changes to [Ctx].
+    pub(crate) fn with_contextual_type(&mut self, ty: Option<Type>) -> WithContextualType<'_, 'scope, 'b> {
+        let len = self.data.contextual_types.len();
+        self.data.contextual_types.push(ty.freezed());
+        WithContextualType { analyzer: self, len }
+    }
+
+    /// Returns the contextual type of the innermost function being validated.
+    pub(crate) fn contextual_type(&self) -> Option<&Type> {
+        self.data.contextual_types.last()?.as_ref()
+    }
+
     fn rule(&self) -> Rule {
         self.env.rule()
     }
@@ -709,6 +783,31 @@ impl<'b, 'c> DerefMut for WithCtx<'_, 'b, 'c> {
     }
 }
 
+pub(crate) struct WithContextualType<'a, 'b, 'c> {
+    analyzer: &'a mut Analyzer<'b, 'c>,
+    len: usize,
+}
+
+impl Drop for WithContextualType<'_, '_, '_> {
+    fn drop(&mut self) {
+        self.analyzer.data.contextual_types.truncate(self.len);
+    }
+}
+
+impl<'b, 'c> Deref for WithContextualType<'_, 'b, 'c> {
+    type Target = Analyzer<'b, 'c>;
+
+    fn deref(&self) -> &Self::Target {
+        self.analyzer
+    }
+}
+
+impl<'b, 'c> DerefMut for WithContextualType<'_, 'b, 'c> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut *self.analyzer
+    }
+}
+
 /// Panics if there's a load request.
 pub struct NoopLoader;
 
@@ -807,6 +906,7 @@ impl Analyzer<'_, '_> {
         if !self.ctx.in_declare {
             self.report_error_for_wrong_top_level_ambient_fns(&m.body);
         }
+        self.report_error_for_overloads_with_wrong_modifiers(&m.body);
 
         if self.config.is_builtin {
             m.body.visit_children_with(self);
@@ -6,7 +6,7 @@ use stc_ts_ast_rnode::{
 use stc_ts_errors::{ErrorKind, Errors};
 use stc_ts_types::{
     Array, ArrayMetadata, CommonTypeMetadata, Instance, Key, KeywordType, PropertySignature, RestType, Tuple, TupleElement, TypeElMetadata,
-    TypeElement, TypeLit, TypeLitMetadata,
+    TypeElement, TypeLit, TypeLitMetadata, TypeParam,
 };
 use stc_ts_utils::PatExt;
 use stc_utils::{cache::Freeze, ext::TypeVecExt};
@@ -168,6 +168,7 @@ impl Analyzer<'_, '_> {
     fn validate(&mut self, node: &RParam) -> VResult<ty::FnParam> {
         node.decorators.visit_with(self);
 
+        self.report_implicit_any_in_binding_pat(&node.pat);
         self.default_any_pat(&node.pat);
 
         let ctx = Ctx {
@@ -406,12 +407,21 @@ impl Analyzer<'_, '_> {
             },
         };
 
-        let ty = match ty {
+        let mut ty = match ty {
             Some(v) => v,
             _ => self.default_type_for_pat(p)?,
         }
         .freezed();
 
+        // Like inferred return types, inferred types of parameters are emitted in
+        // `.d.ts` files, so type arguments with default values should be filled.
+        if self.ctx.is_fn_param && p.get_ty().is_none() {
+            if let Type::Ref(r) = ty.normalize() {
+                let r = r.clone();
+                ty = Type::Ref(self.qualify_ref_type_args(r.span, r)?).freezed();
+            }
+        }
+
         if p.get_ty().is_none() {
             if let Some(node_id) = p.node_id() {
                 if let Some(m) = &mut self.mutations {
@@ -432,19 +442,43 @@ impl Analyzer<'_, '_> {
         Ok(ty::FnParam {
             span: p.span(),
             pat: p.clone(),
-            required: match p {
-                RPat::Ident(i) => !i.id.optional,
-                RPat::Array(arr) => !arr.optional,
-                RPat::Object(obj) => !obj.optional,
-                RPat::Assign(..) => false,
-                RPat::Rest(..) => false,
-                _ => true,
-            },
+            required: is_param_required(p),
             ty: box ty,
         })
     }
 }
 
+/// Returns `true` if `ty` can be the type of a rest parameter.
+///
+/// A type parameter is allowed if it's constrained to an array type, like
+/// `A` of `<A extends any[]>(...args: A) => void`.
+pub(super) fn is_valid_rest_param_type(ty: &Type) -> bool {
+    match ty.normalize() {
+        Type::Array(..) | Type::Tuple(..) => true,
+        Type::Keyword(KeywordType {
+            kind: TsKeywordTypeKind::TsAnyKeyword,
+            ..
+        }) => true,
+        Type::Param(TypeParam {
+            constraint: Some(constraint),
+            ..
+        }) => is_valid_rest_param_type(constraint),
+        _ => false,
+    }
+}
+
+/// Returns `false` if the parameter declared with `p` is optional.
+pub(super) fn is_param_required(p: &RPat) -> bool {
+    match p {
+        RPat::Ident(i) => !i.id.optional,
+        RPat::Array(arr) => !arr.optional,
+        RPat::Object(obj) => !obj.optional,
+        RPat::Assign(..) => false,
+        RPat::Rest(..) => false,
+        _ => true,
+    }
+}
+
 #[validator]
 impl Analyzer<'_, '_> {
     fn validate(&mut self, p: &RRestPat) {
@@ -470,13 +504,8 @@ impl Analyzer<'_, '_> {
             let res: Result<_, _> = try {
                 let ty = type_ann.validate_with(self)?;
 
-                match *ty.normalize() {
-                    Type::Array(..)
-                    | Type::Keyword(KeywordType {
-                        kind: TsKeywordTypeKind::TsAnyKeyword,
-                        ..
-                    }) => {}
-                    _ => Err(ErrorKind::TS2370 { span: p.dot3_token })?,
+                if !is_valid_rest_param_type(&ty) {
+                    Err(ErrorKind::TS2370 { span: p.dot3_token })?
                 }
             };
 
@@ -428,10 +428,13 @@ impl Analyzer<'_, '_> {
                             .ok()
                     });
 
+                let this_from_this_type = self.scope.this_from_this_type().cloned();
+
                 self.with_child(ScopeKind::Method { is_static: false }, Default::default(), {
                     |child: &mut Analyzer| -> VResult<_> {
                         child.ctx.in_async = p.function.is_async;
                         child.ctx.in_generator = p.function.is_generator;
+                        child.scope.this = this_from_this_type;
 
                         child.apply_fn_type_ann(p.function.span, p.function.params.iter().map(|v| &v.pat), method_type_ann.as_ref());
 
@@ -472,9 +475,7 @@ impl Analyzer<'_, '_> {
                                     inferred_ret_ty
                                 };
 
-                                if let Some(m) = &mut child.mutations {
-                                    m.for_fns.entry(p.function.node_id).or_default().ret_ty = Some(inferred_ret_ty.clone());
-                                }
+                                child.record_return_type_of_fn(p.function.span, p.function.node_id, &inferred_ret_ty);
                             }
 
                             inferred = Some(inferred_ret_ty)
@@ -44,7 +44,7 @@ use crate::{
         generic::InferTypeOpts,
         scope::vars::DeclareVarsOpts,
         stmt::return_type::ReturnValues,
-        Analyzer, Ctx, ResultExt,
+        Analyzer, Ctx, ResultExt, DEFAULT_MAX_EXPAND_DEPTH,
     },
     loader::ModuleInfo,
     ty::{self, Alias, Interface, Ref, Tuple, Type, TypeExt, TypeLit, Union},
@@ -74,6 +74,9 @@ pub(crate) struct Scope<'a> {
     pub declaring_parameters: Vec<Id>,
 
     pub declared_return_type: Option<Type>,
+    /// Return type of the contextual signature, used while validating a
+    /// function without a return type annotation.
+    pub contextual_return_type: Option<Type>,
 
     pub declaring_type_params: FxHashSet<Id>,
 
@@ -81,7 +84,9 @@ pub(crate) struct Scope<'a> {
     types: FxHashMap<Id, Type>,
     pub(super) facts: CondFacts,
 
-    pub(super) declaring_fn: Option<Id>,
+    /// The function being declared, with the signatures of its overloads if
+    /// it's the implementation of an overloaded function.
+    pub(super) declaring_fn: Option<(Id, Option<Type>)>,
     /// [Some] while declaring a class property or a property of an object
     /// literal.
     pub(super) declaring_prop: Option<Id>,
@@ -101,6 +106,11 @@ pub(crate) struct Scope<'a> {
 
     pub(super) this_object_members: Vec<TypeElement>,
 
+    /// `T` of `ThisType<T>` in the contextual type of an object literal.
+    ///
+    /// Used as the type of `this` in methods of the object literal.
+    pub(super) this_from_this_type: Option<Type>,
+
     pub(super) super_class: Option<Box<Type>>,
 
     pub(super) return_values: ReturnValues,
@@ -178,11 +188,28 @@ impl Scope<'_> {
                 return false;
             }
 
-            matches!(scope.kind, ScopeKind::Fn | ScopeKind::Method { .. })
+            // Arrow functions use `arguments` of the parent function.
+            matches!(scope.kind, ScopeKind::Fn | ScopeKind::Constructor | ScopeKind::Method { .. })
         })
         .is_some()
     }
 
+    /// Returns true if `arguments` of a function containing a class is
+    /// referenced from a property initializer or a static block of the class.
+    pub fn is_arguments_in_class_initializer(&self) -> bool {
+        let boundary = self.first_kind(|kind| {
+            matches!(
+                kind,
+                ScopeKind::Fn | ScopeKind::Constructor | ScopeKind::Method { .. } | ScopeKind::Class | ScopeKind::ClassStaticBlock
+            )
+        });
+
+        match boundary {
+            Some(scope) => matches!(scope.kind, ScopeKind::Class | ScopeKind::ClassStaticBlock) && self.is_arguments_implicitly_defined(),
+            None => false,
+        }
+    }
+
     pub fn is_declaring(&self, id: &Id) -> bool {
         if self.declaring.contains(id) || self.declaring_parameters.contains(id) {
             return true;
@@ -239,18 +266,40 @@ impl Scope<'_> {
     }
 
     pub fn is_declaring_fn(&self, id: &Id) -> bool {
-        if let Some(d) = &self.declaring_fn {
+        if let Some((d, _)) = &self.declaring_fn {
             if *d == *id {
                 return true;
             }
         }
 
+        // Parameters and the signature of an annotated function shadow the
+        // function being declared.
+        if matches!(self.vars.get(id), Some(VarInfo { copied: false, .. })) {
+            return false;
+        }
+
         match self.parent {
             Some(scope) => scope.is_declaring_fn(id),
             _ => false,
         }
     }
 
+    /// Returns the signatures of overloads if `id` is the implementation of
+    /// an overloaded function being declared.
+    pub fn overloads_of_declaring_fn(&self, id: &Id) -> Option<&Type> {
+        if let Some((d, overloads)) = &self.declaring_fn {
+            if *d == *id {
+                return overloads.as_ref();
+            }
+        }
+
+        if matches!(self.vars.get(id), Some(VarInfo { copied: false, .. })) {
+            return None;
+        }
+
+        self.parent?.overloads_of_declaring_fn(id)
+    }
+
     pub fn get_this_class_name(&self) -> Option<Id> {
         match &self.this_class_name {
             Some(v) => return Some(v.clone()),
@@ -376,6 +425,7 @@ impl Scope<'_> {
             declaring: self.declaring,
             declaring_parameters: self.declaring_parameters,
             declared_return_type: self.declared_return_type,
+            contextual_return_type: self.contextual_return_type,
             declaring_type_params: self.declaring_type_params,
             vars: self.vars,
             types: self.types,
@@ -386,6 +436,7 @@ impl Scope<'_> {
             this_class_name: self.this_class_name,
             this_class_members: self.this_class_members,
             this_object_members: self.this_object_members,
+            this_from_this_type: self.this_from_this_type,
             super_class: self.super_class,
             return_values: self.return_values,
             expand_triage_depth: self.expand_triage_depth,
@@ -501,6 +552,22 @@ impl Scope<'_> {
         self.parent?.declared_return_type()
     }
 
+    pub fn contextual_return_type(&self) -> Option<&Type> {
+        match &self.contextual_return_type {
+            Some(v) => {
+                v.assert_clone_cheap();
+                return Some(v);
+            }
+            None => {}
+        }
+        match self.kind {
+            ScopeKind::Fn | ScopeKind::Method { .. } | ScopeKind::Constructor | ScopeKind::ArrowFn => return None,
+            _ => {}
+        }
+
+        self.parent?.contextual_return_type()
+    }
+
     pub fn remove_declaring<I>(&mut self, names: impl IntoIterator<IntoIter = I, Item = Id>)
     where
         I: Iterator<Item = Id> + DoubleEndedIterator,
@@ -635,6 +702,30 @@ impl Scope<'_> {
         self.parent?.this()
     }
 
+    /// Returns `T` of `ThisType<T>` in the contextual type of the closest
+    /// object literal or the object literals containing it.
+    pub fn this_from_this_type(&self) -> Option<&Type> {
+        match self.kind {
+            ScopeKind::ObjectLit => {
+                if let Some(ty) = &self.this_from_this_type {
+                    return Some(ty);
+                }
+            }
+
+            ScopeKind::Fn
+            | ScopeKind::ArrowFn
+            | ScopeKind::Method { .. }
+            | ScopeKind::Constructor
+            | ScopeKind::Class
+            | ScopeKind::ClassStaticBlock
+            | ScopeKind::Module => return None,
+
+            ScopeKind::TypeParams | ScopeKind::Call | ScopeKind::Flow | ScopeKind::Block | ScopeKind::LoopBody { .. } => {}
+        }
+
+        self.parent?.this_from_this_type()
+    }
+
     pub fn this_class_name(&self) -> Option<Id> {
         if let Some(ref id) = self.this_class_name {
             return Some(id.clone());
@@ -1394,7 +1485,19 @@ impl Analyzer<'_, '_> {
 
         if self.ctx.in_global {
             match kind {
-                VarKind::Var(_) | VarKind::Class | VarKind::Fn | VarKind::Enum => {
+                VarKind::Fn => {
+                    if let Some(ty) = ty.clone() {
+                        // Overloads of a global function are merged, like the ones declared in the
+                        // same scope.
+                        let ty = match self.env.get_global_var(span, name.sym()) {
+                            Ok(prev) if !prev.type_eq(&ty) && is_fn_or_overloads(&prev) => Type::union(vec![prev, ty]).freezed(),
+                            _ => ty,
+                        };
+
+                        self.env.declare_global_var(name.sym().clone(), ty);
+                    }
+                }
+                VarKind::Var(_) | VarKind::Class | VarKind::Enum => {
                     // TODO: Default to any?
                     if let Some(ty) = ty.clone() {
                         self.env.declare_global_var(name.sym().clone(), ty);
@@ -1516,7 +1619,10 @@ impl Analyzer<'_, '_> {
                                 }
                             }
                         }
-                        if ty.is_kwd(TsKeywordTypeKind::TsUnknownKeyword) || var_ty.type_eq(&ty) {
+                        if ty.is_kwd(TsKeywordTypeKind::TsUnknownKeyword)
+                            || var_ty.type_eq(&ty)
+                            || (kind == VarKind::Fn && self.has_identical_overload(&var_ty, &ty))
+                        {
                             var_ty
                         } else {
                             Type::union(vec![var_ty, ty]).freezed()
@@ -1572,8 +1678,55 @@ impl Analyzer<'_, '_> {
         Ok(ty)
     }
 
+    /// Returns `true` if `overloads` has a signature identical to `new`.
+    ///
+    /// Names of parameters and type parameters are ignored, so
+    /// `<T>(a: T) => T` is identical to `<U>(b: U) => U`.
+    fn has_identical_overload(&mut self, overloads: &Type, new: &Type) -> bool {
+        let new = match new.normalize() {
+            Type::Function(f) => f,
+            _ => return false,
+        };
+
+        overloads.iter_union().any(|prev| match prev.normalize() {
+            Type::Function(prev) => self.is_identical_signature(prev, new),
+            _ => false,
+        })
+    }
+
+    fn is_identical_signature(&mut self, l: &ty::Function, r: &ty::Function) -> bool {
+        let (l_decl, r_decl) = match (&l.type_params, &r.type_params) {
+            (None, None) => return l.type_eq(r),
+            (Some(l_decl), Some(r_decl)) if l_decl.params.len() == r_decl.params.len() => (l_decl, r_decl),
+            _ => return false,
+        };
+
+        // Type parameters of `r` are replaced with the ones of `l`.
+        let map = r_decl
+            .params
+            .iter()
+            .zip(l_decl.params.iter())
+            .map(|(r, l)| (r.name.clone(), Type::Param(l.clone()).freezed()))
+            .collect::<FxHashMap<_, _>>();
+
+        for (l_param, r_param) in l_decl.params.iter().zip(r_decl.params.iter()) {
+            match self.expand_type_params(&map, r_param.constraint.as_deref().cloned(), Default::default()) {
+                Ok(constraint) if constraint.type_eq(&l_param.constraint.as_deref().cloned()) => {}
+                _ => return false,
+            }
+        }
+
+        let params = self.expand_type_params(&map, r.params.clone(), Default::default());
+        let ret_ty = self.expand_type_params(&map, Some((*r.ret_ty).clone()), Default::default());
+
+        match (params, ret_ty) {
+            (Ok(params), Ok(Some(ret_ty))) => params.type_eq(&l.params) && ret_ty.type_eq(&*l.ret_ty),
+            _ => false,
+        }
+    }
+
     /// Returns [Err] if overload is wrong.
-    fn validate_fn_overloads(&mut self, span: Span, orig: &Type, new: &Type) -> VResult<()> {
+    pub(super) fn validate_fn_overloads(&mut self, span: Span, orig: &Type, new: &Type) -> VResult<()> {
         // We validates using the signature of implementing function.
         // TODO(kdy1): Validate using last element, when there's a no function decl with
         // body.
@@ -1595,6 +1748,7 @@ impl Analyzer<'_, '_> {
                 )
                 .convert_err(|err| ErrorKind::IncompatibleFnOverload {
                     span: orig.span(),
+                    impl_span: new.span(),
                     cause: box err.into(),
                 })
                 .context("tried to validate signatures of overloaded functions")?;
@@ -1776,6 +1930,7 @@ impl<'a> Scope<'a> {
             declaring: Default::default(),
             declaring_parameters: Default::default(),
             declared_return_type: None,
+            contextual_return_type: None,
             declaring_type_params: Default::default(),
             vars: Default::default(),
             types: Default::default(),
@@ -1786,6 +1941,7 @@ impl<'a> Scope<'a> {
             this_class_name: Default::default(),
             this_class_members: Default::default(),
             this_object_members: Default::default(),
+            this_from_this_type: None,
             super_class: None,
             return_values: Default::default(),
             expand_triage_depth: 0,
@@ -1981,6 +2137,22 @@ impl Expander<'_, '_, '_> {
                     return Ok(Some(Type::any(span, Default::default())));
                 }
 
+                // `Awaited<Promise<T>>` of the builtin library is `T`, and we don't need to
+                // evaluate the recursive conditional type.
+                if &*i.sym == "Awaited"
+                    && self.analyzer.find_local_type(&i.into()).is_none()
+                    && !self.analyzer.imports_by_id.contains_key(&Id::from(i))
+                {
+                    if let Some(TypeParamInstantiation { params, .. }) = type_args {
+                        if let [arg] = &**params {
+                            let arg = arg.clone().fold_with(self);
+                            if let Some(ty) = self.analyzer.awaited_type_of_promise(span, &arg)? {
+                                return Ok(Some(ty));
+                            }
+                        }
+                    }
+                }
+
                 info!("Info: {}{:?}", i.sym, i.span.ctxt);
                 if !trying_primitive_expansion && self.dejavu.contains(&i.into()) {
                     error!("Dejavu: {}{:?}", &i.sym, i.span.ctxt);
@@ -2201,7 +2373,19 @@ impl Expander<'_, '_, '_> {
             return Ok(None);
         }
 
-        let mut ty = self.expand_ts_entity_name(span, &type_name, type_args.as_deref(), was_top_level, trying_primitive_expansion)?;
+        let max_depth = self.analyzer.data.max_expand_depth.unwrap_or(DEFAULT_MAX_EXPAND_DEPTH);
+        if self.analyzer.data.expand_depth >= max_depth {
+            self.analyzer
+                .storage
+                .report(ErrorKind::InstantiationExcessivelyDeep { span }.into());
+
+            return Ok(Some(Type::any(r_span, Default::default())));
+        }
+
+        self.analyzer.data.expand_depth += 1;
+        let ty = self.expand_ts_entity_name(span, &type_name, type_args.as_deref(), was_top_level, trying_primitive_expansion);
+        self.analyzer.data.expand_depth -= 1;
+        let mut ty = ty?;
 
         if let Some(ty) = &mut ty {
             ty.reposition(r_span);
@@ -2654,3 +2838,11 @@ impl VisitMut<Type> for ShallowNormalizer<'_, '_, '_> {
         }
     }
 }
+
+fn is_fn_or_overloads(ty: &Type) -> bool {
+    match ty.normalize() {
+        Type::Function(..) => true,
+        Type::Union(u) => u.types.iter().all(is_fn_or_overloads),
+        _ => false,
+    }
+}
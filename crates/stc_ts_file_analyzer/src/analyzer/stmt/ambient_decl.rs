@@ -1,6 +1,6 @@
 use rnode::VisitWith;
 use stc_ts_ast_rnode::RModuleItem;
-use stc_ts_simple_ast_validations::ambient_fn::AmbientFunctionHandler;
+use stc_ts_simple_ast_validations::{ambient_fn::AmbientFunctionHandler, overload_modifiers::OverloadModifierHandler};
 
 use crate::analyzer::Analyzer;
 
@@ -19,4 +19,16 @@ impl Analyzer<'_, '_> {
 
         visitor.handle_missing_impl();
     }
+
+    pub(crate) fn report_error_for_overloads_with_wrong_modifiers(&mut self, nodes: &[RModuleItem]) {
+        if self.config.is_builtin {
+            return;
+        }
+
+        OverloadModifierHandler {
+            in_declare: self.ctx.in_declare,
+            errors: &mut self.storage,
+        }
+        .check(nodes);
+    }
 }
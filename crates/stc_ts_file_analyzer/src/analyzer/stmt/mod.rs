@@ -45,7 +45,8 @@ impl Analyzer<'_, '_> {
         warn!("Statement start");
         let start = Instant::now();
 
-        if self.rule().always_strict && !self.rule().allow_unreachable_code && self.ctx.in_unreachable {
+        let is_marked_unreachable = self.data.unreachable_stmts.remove(&span);
+        if self.rule().always_strict && !self.rule().allow_unreachable_code && (self.ctx.in_unreachable || is_marked_unreachable) {
             self.storage.report(ErrorKind::UnreachableCode { span: s.span() }.into());
         }
 
@@ -3,17 +3,21 @@
 use std::{borrow::Cow, mem::take, ops::AddAssign};
 
 use rnode::{Fold, FoldWith, Visit, VisitWith};
-use stc_ts_ast_rnode::{RBreakStmt, RIdent, RReturnStmt, RStmt, RStr, RThrowStmt, RTsEntityName, RTsLit, RYieldExpr};
+use stc_ts_ast_rnode::{
+    RArrowExpr, RBreakStmt, RClass, RDecl, RDoWhileStmt, RForInStmt, RForOfStmt, RForStmt, RFunction, RIdent, RReturnStmt, RStmt, RStr,
+    RSwitchStmt, RThrowStmt, RTsEntityName, RTsLit, RWhileStmt, RYieldExpr,
+};
 use stc_ts_errors::{DebugExt, ErrorKind};
 use stc_ts_simple_ast_validations::yield_check::YieldValueUsageFinder;
 use stc_ts_types::{
     CommonTypeMetadata, IndexedAccessType, Key, KeywordType, KeywordTypeMetadata, LitType, MethodSignature, Operator, PropertySignature,
-    Ref, RefMetadata, TypeElement, TypeParamInstantiation,
+    QueryExpr, QueryType, Ref, RefMetadata, TypeElement, TypeParamInstantiation,
 };
 use stc_utils::{
     cache::Freeze,
     ext::{SpanExt, TypeVecExt},
 };
+use swc_atoms::JsWord;
 use swc_common::{Span, Spanned, SyntaxContext, TypeEq, DUMMY_SP};
 use swc_ecma_ast::*;
 use tracing::debug;
@@ -22,7 +26,7 @@ use crate::{
     analyzer::{
         assign::AssignOpts,
         expr::{GetIteratorOpts, TypeOfMode},
-        scope::ExpandOpts,
+        scope::{ExpandOpts, Scope, ScopeKind},
         util::ResultExt,
         Analyzer, Ctx,
     },
@@ -82,6 +86,16 @@ impl Analyzer<'_, '_> {
             }
         }
 
+        // Only function expressions and arrow functions are inferred to return `never`,
+        // so `function fail() { throw e }` returns `void`. A declared return type is
+        // still checked against `never`.
+        let can_be_never = self.scope.declared_return_type().is_some()
+            || match self.scope.kind() {
+                ScopeKind::ArrowFn => true,
+                ScopeKind::Fn => self.ctx.in_fn_expr,
+                _ => false,
+            };
+
         let cannot_fallback_to_iterable_iterator = self.rule().strict_null_checks && {
             let mut v = YieldValueUsageFinder::default();
 
@@ -93,21 +107,32 @@ impl Analyzer<'_, '_> {
         // let mut old_ret_tys = self.scope.return_types.take();
 
         let mut ret_ty = (|| -> VResult<_> {
-            let mut values: ReturnValues = {
+            let (mut values, end_reachable): (ReturnValues, bool) = {
                 let ctx = Ctx {
                     cannot_fallback_to_iterable_iterator,
+                    in_fn_expr: false,
                     ..self.ctx
                 };
                 self.with_ctx(ctx).with(|analyzer: &mut Analyzer| {
+                    analyzer.mark_unreachable_stmts(stmts);
+
                     analyzer.validate_stmts_and_collect(&stmts.iter().collect::<Vec<_>>());
 
-                    take(&mut analyzer.scope.return_values)
+                    let end_reachable = !analyzer.ctx.in_unreachable && can_complete_normally(stmts);
+
+                    (take(&mut analyzer.scope.return_values), end_reachable)
                 })
             };
 
             {
-                //  Expand return types if no element references a type parameter
-                let can_expand = !values.return_types.iter().any(should_preserve_ref);
+                //  Expand return types if no element references a type parameter.
+                //
+                // `typeof f` of the function being declared can't be expanded either, because
+                // it requires the return type being inferred.
+                let can_expand = !values
+                    .return_types
+                    .iter()
+                    .any(|ty| should_preserve_ref(ty) || refers_to_declaring_fn(&self.scope, ty));
 
                 if can_expand {
                     values.return_types = values
@@ -152,7 +177,7 @@ impl Analyzer<'_, '_> {
             }
 
             {
-                if let Some(span) = unconditional_throw {
+                if let Some(span) = unconditional_throw.filter(|_| can_be_never) {
                     values.return_types.push(Type::never(span, Default::default()));
                 }
             }
@@ -169,7 +194,24 @@ impl Analyzer<'_, '_> {
                 actual.push(ty);
             }
 
+            // A body which never completes normally, because of `throw` or a call to a
+            // function returning `never`, has `never` as the return type.
+            if actual.is_empty() && !is_generator && !end_reachable && can_be_never {
+                actual.push(Type::never(span, Default::default()));
+            }
+
+            // If control can fall off the end of the body, `undefined` is returned
+            // implicitly.
+            if self.rule().strict_null_checks
+                && !is_generator
+                && end_reachable
+                && actual.iter().any(|ty| !ty.is_kwd(TsKeywordTypeKind::TsVoidKeyword))
+            {
+                actual.push(Type::undefined(span, Default::default()));
+            }
+
             if is_generator {
+                let has_yield = !values.yield_types.is_empty();
                 let mut types = Vec::with_capacity(values.yield_types.len());
 
                 let is_all_null_or_undefined = values.yield_types.iter().all(|ty| ty.is_null_or_undefined());
@@ -192,7 +234,10 @@ impl Analyzer<'_, '_> {
                     }
                 }
 
-                let yield_ty = if types.is_empty() {
+                let yield_ty = if types.is_empty() && !has_yield {
+                    // A generator without `yield` never yields a value.
+                    Type::never(span, Default::default())
+                } else if types.is_empty() {
                     Type::any(
                         DUMMY_SP,
                         KeywordTypeMetadata {
@@ -251,27 +296,26 @@ impl Analyzer<'_, '_> {
             }
 
             if is_async {
-                let ret_ty = if actual.is_empty() {
+                // Returned promises are flattened, so `Promise<Promise<T>>` is not possible.
+                let mut awaited = Vec::with_capacity(actual.len());
+                for ty in actual {
+                    awaited.push(self.get_awaited_type(span, Cow::Owned(ty))?.into_owned());
+                }
+
+                let ret_ty = if awaited.is_empty() {
                     Type::void(span, Default::default())
                 } else {
-                    self.simplify(Type::union(actual))
+                    self.simplify(Type::union(awaited))
                 };
 
-                return Ok(Some(Type::Ref(Ref {
-                    span,
-                    type_name: RTsEntityName::Ident(RIdent::new("Promise".into(), DUMMY_SP)),
-                    type_args: Some(box TypeParamInstantiation {
-                        span,
-                        params: vec![ret_ty],
-                    }),
-                    metadata: Default::default(),
-                    tracker: Default::default(),
-                })));
+                return Ok(Some(promise_type(span, ret_ty)));
             }
 
             let is_all_null_or_undefined = actual.iter().all(|ty| ty.is_null_or_undefined());
 
-            if !actual.is_empty() && is_all_null_or_undefined {
+            // `null` and `undefined` are widened to `any` only if `strictNullChecks` is
+            // disabled.
+            if !actual.is_empty() && is_all_null_or_undefined && !self.rule().strict_null_checks {
                 return Ok(Some(Type::any(span, Default::default())));
             }
 
@@ -318,6 +362,35 @@ impl Analyzer<'_, '_> {
 
         Ok(ret_ty)
     }
+
+    /// Marks the first statement after a statement which cannot complete
+    /// normally, like `return` or `throw`, in each block of the body.
+    ///
+    /// The marked statements are reported while validating them, so a
+    /// statement which is also unreachable according to the control flow
+    /// analysis is reported only once. Nested functions are checked while
+    /// validating their bodies.
+    fn mark_unreachable_stmts(&mut self, stmts: &[RStmt]) {
+        if !self.rule().always_strict || self.rule().allow_unreachable_code || self.ctx.in_unreachable {
+            return;
+        }
+
+        let mut spans = vec![];
+        find_unreachable_stmts(stmts, &mut spans);
+
+        self.data.unreachable_stmts.extend(spans);
+    }
+}
+
+/// Returns `Promise<ty>`.
+pub(in crate::analyzer) fn promise_type(span: Span, ty: Type) -> Type {
+    Type::Ref(Ref {
+        span,
+        type_name: RTsEntityName::Ident(RIdent::new("Promise".into(), DUMMY_SP)),
+        type_args: Some(box TypeParamInstantiation { span, params: vec![ty] }),
+        metadata: Default::default(),
+        tracker: Default::default(),
+    })
 }
 
 #[validator]
@@ -333,7 +406,11 @@ impl Analyzer<'_, '_> {
             };
             let mut a = self.with_ctx(ctx);
 
-            let type_ann = a.scope.declared_return_type().cloned();
+            let type_ann = a
+                .scope
+                .declared_return_type()
+                .map(return_type_of_body)
+                .or_else(|| a.scope.contextual_return_type().cloned());
             node.arg.validate_with_args(&mut *a, (TypeOfMode::RValue, None, type_ann.as_ref()))
         } {
             res?
@@ -348,7 +425,7 @@ impl Analyzer<'_, '_> {
         debug_assert_ne!(ty.span(), DUMMY_SP, "{:?}", ty);
         ty.freeze();
 
-        if let Some(declared) = self.scope.declared_return_type().cloned() {
+        if let Some(declared) = self.scope.declared_return_type().map(return_type_of_body) {
             match (self.ctx.in_async, self.ctx.in_generator) {
                 // AsyncGenerator
                 (true, true) => {
@@ -450,6 +527,11 @@ impl Analyzer<'_, '_> {
     fn validate(&mut self, e: &RYieldExpr) -> VResult<Type> {
         let span = e.span;
 
+        // Arrow functions can't be generators, so `yield` in them is reported too.
+        if !self.ctx.in_generator {
+            self.storage.report(ErrorKind::YieldOutsideGenerator { span }.into());
+        }
+
         if let Some(res) = e.arg.validate_with_default(self) {
             let ty = res?;
 
@@ -463,6 +545,11 @@ impl Analyzer<'_, '_> {
                         .context("tried to convert argument as an iterator for delegating yield")?
                         .into_owned()
                 }
+            } else if self.ctx.in_async {
+                // Values yielded by async generators are awaited.
+                self.get_awaited_type(e.span, Cow::Owned(ty))
+                    .context("tried to get the awaited type of a value yielded by an async generator")?
+                    .into_owned()
             } else {
                 ty
             }
@@ -547,6 +634,188 @@ impl Visit<RReturnStmt> for LoopBreakerFinder {
     }
 }
 
+/// Finds `break` statements which exit a `switch` statement.
+#[derive(Default)]
+struct BreakFinder {
+    /// The label of the `switch` statement.
+    label: Option<JsWord>,
+    /// `true` in a nested loop or `switch` statement, where an unlabeled
+    /// `break` exits the nested statement instead.
+    in_nested: bool,
+    found: bool,
+}
+
+impl Visit<RBreakStmt> for BreakFinder {
+    fn visit(&mut self, s: &RBreakStmt) {
+        match &s.label {
+            Some(label) => {
+                if self.label.as_ref() == Some(&label.sym) {
+                    self.found = true;
+                }
+            }
+            None => {
+                if !self.in_nested {
+                    self.found = true;
+                }
+            }
+        }
+    }
+}
+
+macro_rules! impl_nested_breakable {
+    ($($T:ty),*) => {
+        $(
+            impl Visit<$T> for BreakFinder {
+                fn visit(&mut self, s: &$T) {
+                    let old = self.in_nested;
+                    self.in_nested = true;
+                    s.visit_children_with(self);
+                    self.in_nested = old;
+                }
+            }
+        )*
+    };
+}
+
+impl_nested_breakable!(RForStmt, RForInStmt, RForOfStmt, RWhileStmt, RDoWhileStmt, RSwitchStmt);
+
+/// `break` can't cross function boundaries.
+impl Visit<RFunction> for BreakFinder {
+    fn visit(&mut self, _: &RFunction) {}
+}
+
+/// `break` can't cross function boundaries.
+impl Visit<RArrowExpr> for BreakFinder {
+    fn visit(&mut self, _: &RArrowExpr) {}
+}
+
+/// Ignore nested classes.
+impl Visit<RClass> for BreakFinder {
+    fn visit(&mut self, _: &RClass) {}
+}
+
+/// Returns true if control can reach the end of `stmts`.
+///
+/// This is a syntactic check. Loops are assumed to complete normally, and
+/// infinite loops are handled by `in_unreachable` of the context instead.
+pub(super) fn can_complete_normally(stmts: &[RStmt]) -> bool {
+    stmts.iter().all(stmt_can_complete_normally)
+}
+
+fn stmt_can_complete_normally(stmt: &RStmt) -> bool {
+    match stmt {
+        RStmt::Return(..) | RStmt::Throw(..) => false,
+        RStmt::Block(s) => can_complete_normally(&s.stmts),
+        RStmt::If(s) => match &s.alt {
+            Some(alt) => stmt_can_complete_normally(&s.cons) || stmt_can_complete_normally(alt),
+            None => true,
+        },
+        RStmt::Switch(s) => switch_can_complete_normally(s, None),
+        RStmt::Labeled(s) => match &*s.body {
+            RStmt::Switch(body) => switch_can_complete_normally(body, Some(&s.label)),
+            _ => true,
+        },
+        RStmt::Try(s) => {
+            if let Some(finalizer) = &s.finalizer {
+                if !can_complete_normally(&finalizer.stmts) {
+                    return false;
+                }
+            }
+
+            can_complete_normally(&s.block.stmts) || s.handler.as_ref().map_or(false, |h| can_complete_normally(&h.body.stmts))
+        }
+        _ => true,
+    }
+}
+
+fn switch_can_complete_normally(s: &RSwitchStmt, label: Option<&RIdent>) -> bool {
+    if s.cases.iter().all(|case| case.test.is_some()) {
+        return true;
+    }
+
+    let mut v = BreakFinder {
+        label: label.map(|label| label.sym.clone()),
+        ..Default::default()
+    };
+    s.cases.visit_with(&mut v);
+    if v.found {
+        return true;
+    }
+
+    s.cases.last().map_or(true, |case| can_complete_normally(&case.cons))
+}
+
+/// Stores the span of the first unreachable statement of `stmts` and nested
+/// blocks to `spans`.
+///
+/// Statements after the unreachable statement are not visited, because only
+/// the first one is reported.
+fn find_unreachable_stmts(stmts: &[RStmt], spans: &mut Vec<Span>) {
+    for (idx, stmt) in stmts.iter().enumerate() {
+        find_unreachable_stmts_in_stmt(stmt, spans);
+
+        if !stmt_can_complete_normally(stmt) {
+            if let Some(unreachable) = stmts[idx + 1..].iter().find(|stmt| !is_hoisted_or_type_only(stmt)) {
+                spans.push(unreachable.span());
+            }
+            return;
+        }
+    }
+}
+
+fn find_unreachable_stmts_in_stmt(stmt: &RStmt, spans: &mut Vec<Span>) {
+    match stmt {
+        RStmt::Block(s) => find_unreachable_stmts(&s.stmts, spans),
+        RStmt::If(s) => {
+            find_unreachable_stmts_in_stmt(&s.cons, spans);
+            if let Some(alt) = &s.alt {
+                find_unreachable_stmts_in_stmt(alt, spans);
+            }
+        }
+        RStmt::Labeled(s) => find_unreachable_stmts_in_stmt(&s.body, spans),
+        RStmt::While(s) => find_unreachable_stmts_in_stmt(&s.body, spans),
+        RStmt::DoWhile(s) => find_unreachable_stmts_in_stmt(&s.body, spans),
+        RStmt::For(s) => find_unreachable_stmts_in_stmt(&s.body, spans),
+        RStmt::ForIn(s) => find_unreachable_stmts_in_stmt(&s.body, spans),
+        RStmt::ForOf(s) => find_unreachable_stmts_in_stmt(&s.body, spans),
+        RStmt::Switch(s) => {
+            for case in &s.cases {
+                find_unreachable_stmts(&case.cons, spans);
+            }
+        }
+        RStmt::Try(s) => {
+            find_unreachable_stmts(&s.block.stmts, spans);
+            if let Some(handler) = &s.handler {
+                find_unreachable_stmts(&handler.body.stmts, spans);
+            }
+            if let Some(finalizer) = &s.finalizer {
+                find_unreachable_stmts(&finalizer.stmts, spans);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Returns true for statements which are not considered unreachable even if
+/// they follow a `return` statement.
+///
+/// ```ts
+/// function foo() {
+///     return bar();
+///
+///     function bar() {}
+///     var x;
+/// }
+/// ```
+fn is_hoisted_or_type_only(stmt: &RStmt) -> bool {
+    match stmt {
+        RStmt::Empty(..) => true,
+        RStmt::Decl(RDecl::Fn(..) | RDecl::TsInterface(..) | RDecl::TsTypeAlias(..)) => true,
+        RStmt::Decl(RDecl::Var(v)) => v.kind == VarDeclKind::Var && v.decls.iter().all(|d| d.init.is_none()),
+        _ => false,
+    }
+}
+
 fn should_preserve_ref(ty: &Type) -> bool {
     match ty {
         Type::IndexedAccessType(..) => true,
@@ -556,6 +825,29 @@ fn should_preserve_ref(ty: &Type) -> bool {
     }
 }
 
+/// Returns true if `ty` contains `typeof f`, where `f` is a function being
+/// declared.
+fn refers_to_declaring_fn(scope: &Scope, ty: &Type) -> bool {
+    let mut v = DeclaringFnQueryFinder { scope, found: false };
+    ty.visit_with(&mut v);
+    v.found
+}
+
+struct DeclaringFnQueryFinder<'a, 'b> {
+    scope: &'a Scope<'b>,
+    found: bool,
+}
+
+impl Visit<QueryType> for DeclaringFnQueryFinder<'_, '_> {
+    fn visit(&mut self, q: &QueryType) {
+        if let QueryExpr::TsEntityName(RTsEntityName::Ident(i)) = &*q.expr {
+            if self.scope.is_declaring_fn(&i.into()) {
+                self.found = true;
+            }
+        }
+    }
+}
+
 /// # Example
 ///
 /// ```ts
@@ -718,3 +1010,17 @@ impl Fold<Type> for KeyInliner<'_, '_, '_> {
         ty
     }
 }
+
+/// Functions with an assertion signature, like `asserts x is string`, return
+/// `void`.
+fn return_type_of_body(declared: &Type) -> Type {
+    match declared.normalize() {
+        Type::Predicate(p) if p.asserts => Type::Keyword(KeywordType {
+            span: p.span,
+            kind: TsKeywordTypeKind::TsVoidKeyword,
+            metadata: Default::default(),
+            tracker: Default::default(),
+        }),
+        _ => declared.clone(),
+    }
+}
@@ -1,6 +1,7 @@
 use rnode::VisitWith;
-use stc_ts_ast_rnode::RCatchClause;
+use stc_ts_ast_rnode::{RCatchClause, RTryStmt};
 
+use super::return_type::can_complete_normally;
 use crate::{
     analyzer::{pat::PatMode, scope::ScopeKind, Analyzer, Ctx},
     validator,
@@ -29,3 +30,27 @@ impl Analyzer<'_, '_> {
             })
     }
 }
+
+#[validator]
+impl Analyzer<'_, '_> {
+    fn validate(&mut self, s: &RTryStmt) {
+        let start = self.scope.return_values.return_types.len();
+
+        s.block.visit_with(self);
+        s.handler.visit_with(self);
+
+        if let Some(finalizer) = &s.finalizer {
+            let end = self.scope.return_values.return_types.len();
+
+            finalizer.visit_with(self);
+
+            // Values returned from `try` and `catch` are discarded if `finally` does not
+            // complete normally.
+            if !can_complete_normally(&finalizer.stmts) {
+                self.scope.return_values.return_types.drain(start..end);
+            }
+        }
+
+        Ok(())
+    }
+}
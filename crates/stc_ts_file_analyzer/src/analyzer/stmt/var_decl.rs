@@ -84,6 +84,12 @@ impl Analyzer<'_, '_> {
         let kind = self.ctx.var_kind;
         let node_id = v.node_id;
 
+        if let RPat::Ident(i) = &v.name {
+            if i.type_ann.is_none() && !self.config.is_builtin {
+                self.data.vars_without_type_ann.insert(i.id.clone().into());
+            }
+        }
+
         let res: Result<_, _> = try {
             let v_span = v.span();
             if !self.config.is_builtin {
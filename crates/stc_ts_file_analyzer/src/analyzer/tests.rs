@@ -6,14 +6,14 @@ use std::{
 use once_cell::sync::Lazy;
 use rnode::{NodeIdGenerator, RNode, VisitWith};
 use stc_testing::logger;
-use stc_ts_ast_rnode::RModule;
+use stc_ts_ast_rnode::{RDecl, RFunction, RModule, RModuleItem, RStmt};
 use stc_ts_builtin_types::Lib;
 use stc_ts_env::{Env, ModuleConfig, Rule};
 use stc_ts_storage::Single;
 use stc_ts_types::{module_id, Id, ModuleId, Type};
 use stc_utils::stack;
-use swc_common::{input::SourceFileInput, FileName, Mark, SourceMap, SyntaxContext};
-use swc_ecma_ast::EsVersion;
+use swc_common::{input::SourceFileInput, FileName, Mark, SourceMap, SyntaxContext, DUMMY_SP};
+use swc_ecma_ast::{EsVersion, Module};
 use swc_ecma_parser::{lexer::Lexer, Parser, StringInput, Syntax, TsConfig};
 use swc_ecma_transforms::resolver;
 use swc_ecma_visit::FoldWith;
@@ -21,9 +21,10 @@ use testing::StdErr;
 use tracing::Level;
 
 use crate::{
-    analyzer::{Analyzer, NoopLoader},
+    analyzer::{Analyzer, Ctx, NoopLoader, ScopeKind},
     env::EnvFactory,
     tests::{GLOBALS, MARKS},
+    validator::ValidateWith,
 };
 
 static ENV: Lazy<Env> = Lazy::new(|| Env::simple(Default::default(), EsVersion::latest(), ModuleConfig::None, &Lib::load("es5")));
@@ -71,6 +72,18 @@ where
 
 impl Tester<'_, '_> {
     pub fn parse(&self, name: &str, src: &str) -> RModule {
+        RModule::from_orig(&mut NodeIdGenerator::invalid(), self.parse_orig(name, src))
+    }
+
+    /// Like [Tester::parse], but assigns valid node ids, which are required by
+    /// caches keyed by node ids.
+    pub fn parse_with_node_ids(&mut self, name: &str, src: &str) -> RModule {
+        let module = self.parse_orig(name, src);
+
+        RModule::from_orig(&mut self.node_id_gen, module)
+    }
+
+    fn parse_orig(&self, name: &str, src: &str) -> Module {
         swc_common::GLOBALS.set(&GLOBALS, || {
             let fm = self.cm.new_source_file(FileName::Real(name.into()), src.into());
 
@@ -88,12 +101,10 @@ impl Tester<'_, '_> {
             );
             let mut parser = Parser::new_from(lexer);
 
-            let module = parser
+            parser
                 .parse_module()
                 .unwrap()
-                .fold_with(&mut resolver(MARKS.unresolved_mark(), self.top_level_mark, true));
-
-            RModule::from_orig(&mut NodeIdGenerator::invalid(), module)
+                .fold_with(&mut resolver(MARKS.unresolved_mark(), self.top_level_mark, true))
         })
     }
 }
@@ -197,3 +208,102 @@ fn get_env() -> Env {
         &libs,
     )
 }
+
+#[test]
+fn contextual_type_is_restored() {
+    run_test(|tester| {
+        let a = &mut tester.analyzer;
+        assert!(a.contextual_type().is_none());
+
+        {
+            let mut outer = a.with_contextual_type(Some(Type::any(DUMMY_SP, Default::default())));
+            assert!(outer.contextual_type().unwrap().is_any());
+
+            {
+                let mut inner = outer.with_contextual_type(Some(Type::unknown(DUMMY_SP, Default::default())));
+                assert!(inner.contextual_type().unwrap().is_unknown());
+
+                // A function without a contextual type hides the outer one.
+                let none = inner.with_contextual_type(None);
+                assert!(none.contextual_type().is_none());
+            }
+
+            assert!(outer.contextual_type().unwrap().is_any());
+        }
+
+        assert!(a.contextual_type().is_none());
+    })
+    .unwrap();
+}
+
+#[test]
+fn contextual_type_is_visible_from_child_scope() {
+    run_test(|tester| {
+        let mut a = tester.analyzer.with_contextual_type(Some(Type::any(DUMMY_SP, Default::default())));
+
+        a.with_child(ScopeKind::ArrowFn, Default::default(), |child: &mut Analyzer| {
+            assert!(child.contextual_type().unwrap().is_any());
+
+            let inner = child.with_contextual_type(Some(Type::unknown(DUMMY_SP, Default::default())));
+            assert!(inner.contextual_type().unwrap().is_unknown());
+
+            Ok(())
+        })
+        .unwrap();
+
+        assert!(a.contextual_type().unwrap().is_any());
+    })
+    .unwrap();
+}
+
+fn first_fn(module: &RModule) -> &RFunction {
+    match &module.body[0] {
+        RModuleItem::Stmt(RStmt::Decl(RDecl::Fn(f))) => &f.function,
+        _ => unreachable!("the first item should be a function declaration"),
+    }
+}
+
+#[test]
+fn fn_type_is_reused() {
+    run_test(|tester| {
+        let module = tester.parse_with_node_ids("test.ts", "function f() { const x: string = 1; }");
+        let f = first_fn(&module);
+        let a = &mut tester.analyzer;
+
+        let first = f.validate_with_args(a, (None, None)).unwrap();
+        assert!(!a.storage.take_errors().is_empty());
+
+        // The body is not validated again, so errors are not reported twice.
+        let second = f.validate_with_args(a, (None, None)).unwrap();
+        assert!(a.storage.take_errors().is_empty());
+        assert_eq!(first, second);
+    })
+    .unwrap();
+}
+
+#[test]
+fn fn_type_is_not_reused_in_other_context() {
+    run_test(|tester| {
+        let module = tester.parse_with_node_ids("test.ts", "function f() { const x: string = 1; }");
+        let f = first_fn(&module);
+        let a = &mut tester.analyzer;
+
+        f.validate_with_args(a, (None, None)).unwrap();
+        a.storage.take_errors();
+
+        {
+            let mut a = a.with_contextual_type(Some(Type::any(DUMMY_SP, Default::default())));
+            f.validate_with_args(&mut *a, (None, None)).unwrap();
+            assert!(!a.storage.take_errors().is_empty());
+        }
+
+        let ctx = Ctx {
+            in_argument: true,
+            ..a.ctx
+        };
+        f.validate_with_args(&mut *a.with_ctx(ctx), (None, None)).unwrap();
+
+        assert_eq!(a.data.fn_types[&f.node_id].len(), 2);
+    })
+    .unwrap();
+}
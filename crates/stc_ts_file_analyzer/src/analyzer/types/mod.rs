@@ -2,7 +2,7 @@ use std::{borrow::Cow, collections::HashMap, fmt::Debug};
 
 use fxhash::FxHashMap;
 use itertools::Itertools;
-use rnode::{NodeId, VisitWith};
+use rnode::{NodeId, Visit, VisitWith};
 use stc_ts_ast_rnode::{RBindingIdent, RExpr, RIdent, RInvalid, RLit, RNumber, RPat, RStr, RTsEntityName, RTsEnumMemberId, RTsLit};
 use stc_ts_base_type_ops::{
     bindings::{collect_bindings, BindingCollector, KnownTypeVisitor},
@@ -16,10 +16,10 @@ use stc_ts_generics::ExpandGenericOpts;
 use stc_ts_type_ops::{tuple_normalization::normalize_tuples, Fix};
 use stc_ts_types::{
     name::Name, Accessor, Array, Class, ClassDef, ClassMember, ClassMetadata, ComputedKey, Conditional, ConditionalMetadata,
-    ConstructorSignature, EnumVariant, FnParam, Id, IdCtx, IndexSignature, IndexedAccessType, Instance, InstanceMetadata, Intersection,
-    IntrinsicKind, Key, KeywordType, KeywordTypeMetadata, LitType, LitTypeMetadata, MethodSignature, Operator, PropertySignature,
-    QueryExpr, QueryType, Ref, StringMapping, ThisType, ThisTypeMetadata, TplElem, TplType, Type, TypeElement, TypeLit, TypeLitMetadata,
-    TypeParam, TypeParamInstantiation, Union,
+    ConstructorSignature, EnumVariant, FnParam, Id, IdCtx, IndexSignature, IndexedAccessType, InferType, Instance, InstanceMetadata,
+    Intersection, IntrinsicKind, Key, KeywordType, KeywordTypeMetadata, LitType, LitTypeMetadata, MethodSignature, Operator,
+    PropertySignature, QueryExpr, QueryType, Ref, StringMapping, ThisType, ThisTypeMetadata, TplElem, TplType, Type, TypeElement, TypeLit,
+    TypeLitMetadata, TypeParam, TypeParamInstantiation, Union,
 };
 use stc_ts_utils::run;
 use stc_utils::{
@@ -1348,6 +1348,19 @@ impl Analyzer<'_, '_> {
                 .ok();
 
             if let Some(type_params) = type_params {
+                // `infer U extends string` does not match if the inferred type does not satisfy
+                // the constraint.
+                let mut finder = InferTypeParamFinder::default();
+                extends_type.visit_with(&mut finder);
+
+                for param in finder.params {
+                    if let (Some(constraint), Some(inferred)) = (&param.constraint, type_params.get(&param.name)) {
+                        if let Some(false) = self.extends(span, inferred, constraint, Default::default()) {
+                            return self.expand_type_params(&type_params, *false_type, Default::default()).unwrap();
+                        }
+                    }
+                }
+
                 check_type = box self.expand_type_params(&type_params, *check_type, Default::default()).unwrap();
                 extends_type = box self.expand_type_params(&type_params, *extends_type, Default::default()).unwrap();
 
@@ -2650,3 +2663,17 @@ fn apply_string_mapping<T: AsRef<str>>(intrinsic: &IntrinsicKind, raw: T) -> Ato
     }
     .into()
 }
+
+/// Collects type parameters declared with `infer`.
+#[derive(Default)]
+struct InferTypeParamFinder {
+    params: Vec<TypeParam>,
+}
+
+impl Visit<InferType> for InferTypeParamFinder {
+    fn visit(&mut self, ty: &InferType) {
+        ty.visit_children_with(self);
+
+        self.params.push(ty.type_param.clone());
+    }
+}
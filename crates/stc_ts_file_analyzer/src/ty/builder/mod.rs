@@ -0,0 +1,203 @@
+use rnode::NodeId;
+use stc_ts_ast_rnode::{RBindingIdent, RIdent, RPat, RRestPat};
+use stc_ts_errors::ErrorKind;
+use stc_ts_types::{FnParam, Function, KeywordType, Type, TypeParam, TypeParamDecl};
+use swc_atoms::{js_word, JsWord};
+use swc_common::Span;
+use swc_ecma_ast::TsKeywordTypeKind;
+
+use crate::VResult;
+
+#[cfg(test)]
+mod tests;
+
+/// Builds a [Function] without parsing source code.
+///
+/// # Example
+///
+/// ```ignore
+/// // <T>(this: Window, a: T, b?: string, ...rest: number[]) => T
+/// let f = FunctionTypeBuilder::new(span)
+///     .type_param(t.clone())
+///     .this_type(window)
+///     .param("a", Type::Param(t.clone()))
+///     .optional_param("b", string)
+///     .rest_param("rest", number_array)
+///     .ret_ty(Type::Param(t))
+///     .build()?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct FunctionTypeBuilder {
+    span: Span,
+    type_params: Vec<TypeParam>,
+    this_ty: Option<Type>,
+    params: Vec<ParamData>,
+    ret_ty: Option<Type>,
+}
+
+#[derive(Debug, Clone)]
+struct ParamData {
+    name: JsWord,
+    ty: Type,
+    kind: ParamKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ParamKind {
+    Required,
+    Optional,
+    Rest,
+}
+
+impl FunctionTypeBuilder {
+    pub fn new(span: Span) -> Self {
+        FunctionTypeBuilder {
+            span,
+            type_params: Default::default(),
+            this_ty: Default::default(),
+            params: Default::default(),
+            ret_ty: Default::default(),
+        }
+    }
+
+    pub fn type_param(mut self, type_param: TypeParam) -> Self {
+        self.type_params.push(type_param);
+        self
+    }
+
+    /// Sets the type of `this` parameter.
+    pub fn this_type(mut self, ty: Type) -> Self {
+        self.this_ty = Some(ty);
+        self
+    }
+
+    pub fn param(self, name: impl Into<JsWord>, ty: Type) -> Self {
+        self.add_param(name.into(), ty, ParamKind::Required)
+    }
+
+    pub fn optional_param(self, name: impl Into<JsWord>, ty: Type) -> Self {
+        self.add_param(name.into(), ty, ParamKind::Optional)
+    }
+
+    /// `ty` should be the type of the whole rest parameter, like `number[]`.
+    pub fn rest_param(self, name: impl Into<JsWord>, ty: Type) -> Self {
+        self.add_param(name.into(), ty, ParamKind::Rest)
+    }
+
+    /// Defaults to `void`.
+    pub fn ret_ty(mut self, ty: Type) -> Self {
+        self.ret_ty = Some(ty);
+        self
+    }
+
+    fn add_param(mut self, name: JsWord, ty: Type, kind: ParamKind) -> Self {
+        self.params.push(ParamData { name, ty, kind });
+        self
+    }
+
+    /// Validates the order of parameters and creates the function type.
+    pub fn build(self) -> VResult<Function> {
+        let span = self.span;
+
+        let mut params = Vec::with_capacity(self.params.len() + usize::from(self.this_ty.is_some()));
+
+        if let Some(ty) = self.this_ty {
+            params.push(FnParam {
+                span,
+                required: true,
+                pat: RPat::Ident(RBindingIdent {
+                    node_id: NodeId::invalid(),
+                    id: RIdent::new(js_word!("this"), span),
+                    type_ann: None,
+                }),
+                ty: box ty,
+            });
+        }
+
+        let mut has_optional = false;
+        let mut has_rest = false;
+        for param in self.params {
+            if has_rest {
+                // A rest parameter must be last in a parameter list.
+                return Err(ErrorKind::TS1014 { span }.into());
+            }
+
+            let pat = match param.kind {
+                ParamKind::Required => {
+                    if has_optional {
+                        // A required parameter cannot follow an optional parameter.
+                        return Err(ErrorKind::TS1016 { span }.into());
+                    }
+
+                    RPat::Ident(RBindingIdent {
+                        node_id: NodeId::invalid(),
+                        id: RIdent::new(param.name, span),
+                        type_ann: None,
+                    })
+                }
+                ParamKind::Optional => {
+                    has_optional = true;
+
+                    RPat::Ident(RBindingIdent {
+                        node_id: NodeId::invalid(),
+                        id: RIdent {
+                            optional: true,
+                            ..RIdent::new(param.name, span)
+                        },
+                        type_ann: None,
+                    })
+                }
+                ParamKind::Rest => {
+                    has_rest = true;
+
+                    if !matches!(param.ty.normalize(), Type::Array(..) | Type::Tuple(..)) && !param.ty.is_any() {
+                        return Err(ErrorKind::TS2370 { span }.into());
+                    }
+
+                    RPat::Rest(RRestPat {
+                        span,
+                        dot3_token: span,
+                        node_id: NodeId::invalid(),
+                        arg: box RPat::Ident(RBindingIdent {
+                            node_id: NodeId::invalid(),
+                            id: RIdent::new(param.name, span),
+                            type_ann: None,
+                        }),
+                        type_ann: None,
+                    })
+                }
+            };
+
+            params.push(FnParam {
+                span,
+                required: param.kind == ParamKind::Required,
+                pat,
+                ty: box param.ty,
+            });
+        }
+
+        Ok(Function {
+            span,
+            type_params: if self.type_params.is_empty() {
+                None
+            } else {
+                Some(TypeParamDecl {
+                    span,
+                    params: self.type_params,
+                    tracker: Default::default(),
+                })
+            },
+            params,
+            ret_ty: box self.ret_ty.unwrap_or_else(|| {
+                Type::Keyword(KeywordType {
+                    span,
+                    kind: TsKeywordTypeKind::TsVoidKeyword,
+                    metadata: Default::default(),
+                    tracker: Default::default(),
+                })
+            }),
+            metadata: Default::default(),
+            tracker: Default::default(),
+        })
+    }
+}
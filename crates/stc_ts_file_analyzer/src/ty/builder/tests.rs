@@ -0,0 +1,106 @@
+use stc_ts_ast_rnode::RPat;
+use stc_ts_types::{Array, Id, KeywordType, Type, TypeParam};
+use swc_common::DUMMY_SP;
+use swc_ecma_ast::TsKeywordTypeKind;
+
+use super::FunctionTypeBuilder;
+
+fn kwd(kind: TsKeywordTypeKind) -> Type {
+    Type::Keyword(KeywordType {
+        span: DUMMY_SP,
+        kind,
+        metadata: Default::default(),
+        tracker: Default::default(),
+    })
+}
+
+fn array(elem_type: Type) -> Type {
+    Type::Array(Array {
+        span: DUMMY_SP,
+        elem_type: box elem_type,
+        metadata: Default::default(),
+        tracker: Default::default(),
+    })
+}
+
+fn type_param(name: &str) -> TypeParam {
+    TypeParam {
+        span: DUMMY_SP,
+        name: Id::word(name.into()),
+        constraint: None,
+        default: None,
+        metadata: Default::default(),
+        tracker: Default::default(),
+    }
+}
+
+#[test]
+fn generic() {
+    let t = type_param("T");
+
+    // <T>(a: T, b?: string) => T
+    let f = FunctionTypeBuilder::new(DUMMY_SP)
+        .type_param(t.clone())
+        .param("a", Type::Param(t.clone()))
+        .optional_param("b", kwd(TsKeywordTypeKind::TsStringKeyword))
+        .ret_ty(Type::Param(t.clone()))
+        .build()
+        .unwrap();
+
+    let type_params = f.type_params.expect("type parameters should be preserved");
+    assert_eq!(type_params.params.len(), 1);
+    assert_eq!(type_params.params[0].name, t.name);
+
+    assert_eq!(f.params.len(), 2);
+    assert!(f.params[0].required);
+    assert!(!f.params[1].required);
+    assert!(matches!(&*f.ret_ty, Type::Param(p) if p.name == t.name));
+}
+
+#[test]
+fn rest() {
+    // (this: number, ...rest: string[]) => void
+    let f = FunctionTypeBuilder::new(DUMMY_SP)
+        .this_type(kwd(TsKeywordTypeKind::TsNumberKeyword))
+        .rest_param("rest", array(kwd(TsKeywordTypeKind::TsStringKeyword)))
+        .build()
+        .unwrap();
+
+    assert_eq!(f.params.len(), 2);
+    assert!(matches!(&f.params[0].pat, RPat::Ident(i) if &*i.id.sym == "this"));
+    assert!(matches!(&f.params[1].pat, RPat::Rest(..)));
+    assert!(!f.params[1].required);
+    assert!(f.ret_ty.is_kwd(TsKeywordTypeKind::TsVoidKeyword));
+}
+
+#[test]
+fn required_after_optional() {
+    let err = FunctionTypeBuilder::new(DUMMY_SP)
+        .optional_param("a", kwd(TsKeywordTypeKind::TsStringKeyword))
+        .param("b", kwd(TsKeywordTypeKind::TsStringKeyword))
+        .build()
+        .unwrap_err();
+
+    assert_eq!(err.code(), 1016);
+}
+
+#[test]
+fn rest_not_last() {
+    let err = FunctionTypeBuilder::new(DUMMY_SP)
+        .rest_param("a", array(kwd(TsKeywordTypeKind::TsStringKeyword)))
+        .param("b", kwd(TsKeywordTypeKind::TsStringKeyword))
+        .build()
+        .unwrap_err();
+
+    assert_eq!(err.code(), 1014);
+}
+
+#[test]
+fn rest_not_array() {
+    let err = FunctionTypeBuilder::new(DUMMY_SP)
+        .rest_param("a", kwd(TsKeywordTypeKind::TsStringKeyword))
+        .build()
+        .unwrap_err();
+
+    assert_eq!(err.code(), 2370);
+}
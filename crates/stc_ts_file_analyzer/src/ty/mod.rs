@@ -3,6 +3,7 @@ use stc_ts_type_ops::{generalization::LitGeneralizer, tuple_to_array::TupleToArr
 pub(crate) use stc_ts_types::*;
 use tracing::instrument;
 
+pub mod builder;
 pub mod type_facts;
 
 pub trait TypeExt: Into<Type> {
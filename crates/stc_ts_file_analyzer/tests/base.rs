@@ -40,6 +40,13 @@ struct StcError {
 }
 
 fn get_env() -> Env {
+    get_env_with_rule(Rule {
+        strict_function_types: true,
+        ..Default::default()
+    })
+}
+
+fn get_env_with_rule(rule: Rule) -> Env {
     let mut libs = vec![];
     let ls = &[
         "es2022.full",
@@ -57,15 +64,72 @@ fn get_env() -> Env {
     libs.sort();
     libs.dedup();
 
-    Env::simple(
-        Rule {
-            strict_function_types: true,
-            ..Default::default()
-        },
-        EsVersion::latest(),
-        ModuleConfig::None,
-        &libs,
-    )
+    Env::simple(rule, EsVersion::latest(), ModuleConfig::None, &libs)
+}
+
+/// Applies directives like `// @noImplicitAny: true` to `rule`.
+///
+/// Unknown directives (e.g. `// @target`) are ignored.
+fn apply_directives(src: &str, rule: &mut Rule) {
+    for line in src.lines() {
+        let line = line.trim();
+        if !line.starts_with("//") {
+            continue;
+        }
+        let line = line["//".len()..].trim();
+        if !line.starts_with('@') {
+            continue;
+        }
+
+        let (name, value) = match line["@".len()..].split_once(':') {
+            Some(v) => v,
+            None => continue,
+        };
+        let value = match value.trim().parse::<bool>() {
+            Ok(v) => v,
+            Err(..) => continue,
+        };
+
+        match &*name.trim().to_ascii_lowercase() {
+            "strict" => {
+                rule.no_implicit_any = value;
+                rule.no_implicit_this = value;
+                rule.always_strict = value;
+                rule.strict_null_checks = value;
+                rule.strict_function_types = value;
+            }
+            "noimplicitany" => rule.no_implicit_any = value,
+            "noimplicitthis" => rule.no_implicit_this = value,
+            "alwaysstrict" => rule.always_strict = value,
+            "strictnullchecks" => rule.strict_null_checks = value,
+            "strictfunctiontypes" => rule.strict_function_types = value,
+            "allowunreachablecode" => rule.allow_unreachable_code = value,
+            "noimplicitreturns" => rule.no_implicit_returns = value,
+            "nounusedparameters" => rule.no_unused_parameters = value,
+            _ => {}
+        }
+    }
+}
+
+/// Returns the flags passed to `tsc` for the directives in `src`.
+fn tsc_flags_from_directives(src: &str) -> Vec<String> {
+    let mut flags = vec![];
+    for line in src.lines() {
+        let line = line.trim();
+        if !line.starts_with("//") {
+            continue;
+        }
+        let line = line["//".len()..].trim();
+        if !line.starts_with('@') {
+            continue;
+        }
+
+        if let Some((name, value)) = line["@".len()..].split_once(':') {
+            flags.push(format!("--{}", name.trim()));
+            flags.push(value.trim().to_string());
+        }
+    }
+    flags
 }
 
 fn validate(input: &Path) -> Vec<StcError> {
@@ -76,7 +140,12 @@ fn validate(input: &Path) -> Vec<StcError> {
 
             let fm = cm.load_file(input).unwrap();
 
-            let env = get_env();
+            let mut rule = Rule {
+                strict_function_types: true,
+                ..Default::default()
+            };
+            apply_directives(&fm.src, &mut rule);
+            let env = get_env_with_rule(rule);
 
             let generator = module_id::ModuleIdGenerator::default();
             let path = Arc::new(FileName::Real(input.to_path_buf()));
@@ -307,7 +376,7 @@ fn compare(input: PathBuf) {
         .into_iter()
         .map(|err| StcError {
             line: err.line,
-            code: err.code,
+            code: ErrorKind::normalize_error_code(err.code),
         })
         .collect_vec();
     expected.sort();
@@ -318,12 +387,15 @@ fn compare(input: PathBuf) {
 }
 
 fn invoke_tsc(input: &Path) -> Vec<TscError> {
+    let src = read_to_string(input).unwrap();
+
     let output = Command::new("npx")
         .arg("tsc")
         .arg("--pretty")
         .arg("--noEmit")
         .arg("--lib")
         .arg("es2020")
+        .args(tsc_flags_from_directives(&src))
         .arg(input)
         .output()
         .expect("failed to invoke tsc");
@@ -376,6 +448,9 @@ fn run_test(file_name: PathBuf, for_error: bool) -> Option<NormalizedOutput> {
                 suppress_excess_property_errors: false,
                 suppress_implicit_any_index_errors: false,
                 use_define_property_for_class_fields: false,
+                use_jsdoc_types: false,
+                emit_decorator_metadata: false,
+                report_unreachable_overloads: false,
                 jsx: JsxMode::Preserve,
             };
 
@@ -0,0 +1,45 @@
+#![feature(box_syntax)]
+
+use stc_ts_env::Rule;
+use stc_ts_file_analyzer::validator::ValidateWith;
+
+use self::common::{find_fn, run};
+
+mod common;
+
+/// Checks `actual` against the signature of `expected` declared in `src`.
+fn check(src: &str) -> bool {
+    let rule = Rule {
+        strict_function_types: true,
+        ..Default::default()
+    };
+
+    run(src, rule, |analyzer, module| {
+        let expected = find_fn(module, "expected")
+            .validate_with_args(analyzer, (None, None))
+            .expect("expected signature should be valid");
+
+        analyzer.check_function_type(find_fn(module, "actual"), &expected).is_ok()
+    })
+    .0
+}
+
+#[test]
+fn matching_signature() {
+    assert!(check(
+        "declare function expected(a: number): string;
+        function actual(a: number) {
+            return 'foo';
+        }"
+    ));
+}
+
+#[test]
+fn mismatching_signature() {
+    assert!(!check(
+        "declare function expected(a: number): string;
+        function actual(a: string) {
+            return 1;
+        }"
+    ));
+}
@@ -0,0 +1,121 @@
+//! Helpers shared by integration tests which drive the analyzer directly.
+#![allow(dead_code)]
+
+use std::{path::PathBuf, sync::Arc};
+
+use rnode::{NodeIdGenerator, RNode, VisitWith};
+use stc_ts_ast_rnode::{RDecl, RFunction, RModule, RModuleItem, RStmt};
+use stc_ts_builtin_types::Lib;
+use stc_ts_env::{Env, ModuleConfig, Rule};
+use stc_ts_errors::ErrorKind;
+use stc_ts_file_analyzer::{
+    analyzer::{Analyzer, NoopLoader},
+    env::EnvFactory,
+};
+use stc_ts_storage::{Info, Single};
+use stc_ts_types::module_id;
+use stc_ts_utils::StcComments;
+use swc_common::{input::SourceFileInput, FileName, SyntaxContext};
+use swc_ecma_ast::EsVersion;
+use swc_ecma_parser::{lexer::Lexer, Parser, Syntax, TsConfig};
+use swc_ecma_transforms::resolver;
+use swc_ecma_visit::FoldWith;
+
+pub fn get_env(rule: Rule) -> Env {
+    let mut libs = Lib::load("es5");
+    libs.sort();
+    libs.dedup();
+
+    Env::simple(rule, EsVersion::latest(), ModuleConfig::None, &libs)
+}
+
+pub fn find_fn<'a>(module: &'a RModule, name: &str) -> &'a RFunction {
+    module
+        .body
+        .iter()
+        .find_map(|item| match item {
+            RModuleItem::Stmt(RStmt::Decl(RDecl::Fn(f))) if &*f.ident.sym == name => Some(&*f.function),
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("function `{}` should exist", name))
+}
+
+/// Parses `src` as `test.ts` and calls `op` with a root analyzer for it.
+///
+/// The module is not validated before `op` is called. Returns the value
+/// returned by `op` and the information stored while analyzing.
+pub fn run<F, Ret>(src: &str, rule: Rule, op: F) -> (Ret, Info)
+where
+    F: FnOnce(&mut Analyzer, &RModule) -> Ret,
+{
+    testing::run_test2(false, |cm, _| {
+        let fm = cm.new_source_file(FileName::Real(PathBuf::from("test.ts")), src.to_string());
+
+        let env = get_env(rule);
+
+        let generator = module_id::ModuleIdGenerator::default();
+        let path = Arc::new(fm.name.clone());
+        let (module_id, top_level_mark) = generator.generate(&path);
+
+        let comments = StcComments::default();
+
+        let mut node_id_gen = NodeIdGenerator::default();
+        let mut module = {
+            let lexer = Lexer::new(
+                Syntax::Typescript(TsConfig {
+                    decorators: true,
+                    ..Default::default()
+                }),
+                EsVersion::Es2021,
+                SourceFileInput::from(&*fm),
+                Some(&comments),
+            );
+            let mut parser = Parser::new_from(lexer);
+
+            parser.parse_module().unwrap()
+        };
+        module = module.fold_with(&mut resolver(env.shared().marks().unresolved_mark(), top_level_mark, true));
+        let module = RModule::from_orig(&mut node_id_gen, module);
+
+        let mut storage = Single {
+            parent: None,
+            id: module_id,
+            top_level_ctxt: SyntaxContext::empty().apply_mark(top_level_mark),
+            path,
+            is_dts: false,
+            info: Default::default(),
+        };
+
+        let ret = {
+            let mut analyzer = Analyzer::root(env, cm, comments.clone(), box &mut storage, &NoopLoader, None);
+
+            op(&mut analyzer, &module)
+        };
+
+        Ok((ret, storage.info))
+    })
+    .unwrap()
+}
+
+/// Returns the codes of errors reported while validating `src`, flattened.
+pub fn error_codes(src: &str, rule: Rule) -> Vec<usize> {
+    error_codes_with(src, rule, |_| {})
+}
+
+/// Same as [error_codes], but calls `configure` with the analyzer before
+/// validating `src`.
+pub fn error_codes_with<F>(src: &str, rule: Rule, configure: F) -> Vec<usize>
+where
+    F: FnOnce(&mut Analyzer),
+{
+    let (_, info) = run(src, rule, |analyzer, module| {
+        configure(analyzer);
+
+        module.visit_with(analyzer)
+    });
+
+    ErrorKind::flatten(info.errors.into_iter().collect())
+        .iter()
+        .map(|e| e.code())
+        .collect()
+}
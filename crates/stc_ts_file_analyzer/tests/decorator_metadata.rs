@@ -0,0 +1,123 @@
+#![feature(box_syntax)]
+
+use stc_ts_ast_rnode::{RClassMember, RDecl, RModuleItem, RPropName, RStmt};
+use stc_ts_env::Rule;
+use stc_ts_file_analyzer::{analyzer::DesignType, validator::ValidateWith};
+
+use self::common::run;
+
+mod common;
+
+/// Returns the `design:paramtypes` metadata recorded for the method `m` of
+/// the class `C` declared in `src`, with names of referenced values.
+fn param_types(src: &str) -> Option<Vec<String>> {
+    let rule = Rule {
+        emit_decorator_metadata: true,
+        ..Default::default()
+    };
+
+    run(src, rule, |analyzer, module| {
+        module.validate_with(analyzer).unwrap();
+
+        let node_id = module
+            .body
+            .iter()
+            .find_map(|item| match item {
+                RModuleItem::Stmt(RStmt::Decl(RDecl::Class(c))) if &*c.ident.sym == "C" => {
+                    c.class.body.iter().find_map(|member| match member {
+                        RClassMember::Method(m) if matches!(&m.key, RPropName::Ident(i) if &*i.sym == "m") => Some(m.function.node_id),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            })
+            .expect("method `C.m` should exist");
+
+        analyzer.design_param_types(node_id).map(|types| {
+            types
+                .iter()
+                .map(|ty| match ty {
+                    DesignType::Ref(name) => format!("Ref({:?})", name),
+                    _ => format!("{:?}", ty),
+                })
+                .collect()
+        })
+    })
+    .0
+}
+
+fn names(types: &[&str]) -> Option<Vec<String>> {
+    Some(types.iter().map(|s| s.to_string()).collect())
+}
+
+#[test]
+fn keywords() {
+    assert_eq!(
+        param_types(
+            "declare function dec(...args: any[]): any;
+            class C {
+                @dec
+                m(a: number, b: string, c: boolean, d: void) {}
+            }"
+        ),
+        names(&["Number", "String", "Boolean", "Void"])
+    );
+}
+
+#[test]
+fn any_fallback() {
+    assert_eq!(
+        param_types(
+            "declare function dec(...args: any[]): any;
+            interface I {
+                a: string;
+            }
+            class C {
+                @dec
+                m(a, b: any, c: unknown, d: I, e: { a: string }, f: string | number) {}
+            }"
+        ),
+        names(&["Object", "Object", "Object", "Object", "Object", "Object"])
+    );
+}
+
+#[test]
+fn literals_arrays_and_functions() {
+    assert_eq!(
+        param_types(
+            "declare function dec(...args: any[]): any;
+            class C {
+                @dec
+                m(a: 'a' | 'b', b: 1, c: string[], d: [number], e: () => void, f: string | null, ...rest: number[]) {}
+            }"
+        ),
+        names(&["String", "Number", "Array", "Array", "Function", "String", "Number"])
+    );
+}
+
+#[test]
+fn parameter_decorator() {
+    let types = param_types(
+        "declare function dec(...args: any[]): any;
+        class D {}
+        class C {
+            m(this: C, @dec a: D) {}
+        }",
+    )
+    .expect("metadata should be recorded");
+
+    assert_eq!(types.len(), 1);
+    assert!(types[0].starts_with("Ref("), "{:?}", types);
+}
+
+#[test]
+fn not_decorated() {
+    assert_eq!(
+        param_types(
+            "class C {
+                m(a: number) {}
+            }"
+        ),
+        None
+    );
+}
@@ -0,0 +1,71 @@
+#![feature(box_syntax)]
+
+use stc_ts_file_analyzer::validator::ValidateWith;
+use stc_ts_storage::Info;
+use stc_ts_types::Type;
+
+use self::common::run;
+
+mod common;
+
+/// Validates `src` and returns the exports and the reported errors.
+fn analyze(src: &str) -> Info {
+    run(src, Default::default(), |analyzer, module| {
+        module.validate_with(analyzer).expect("module should be valid");
+    })
+    .1
+}
+
+/// Returns the number of type arguments of the type of the first parameter of
+/// the exported function `f` declared in `src`.
+fn type_arg_count_of_param(src: &str) -> usize {
+    let info = analyze(src);
+
+    let f = info.exports.vars.get(&"f".into()).expect("`f` should be exported");
+    let param = match f.normalize() {
+        Type::Function(f) => &f.params[0],
+        _ => panic!("`f` should be a function, got {:?}", f),
+    };
+
+    match param.ty.normalize() {
+        Type::Ref(r) => r.type_args.as_ref().map_or(0, |args| args.params.len()),
+        _ => panic!("parameter should be a reference, got {:?}", param.ty),
+    }
+}
+
+#[test]
+fn inferred_param_without_type_args() {
+    assert_eq!(
+        type_arg_count_of_param(
+            "interface Foo<T = number, U = string> {}
+            declare function make(): Foo;
+            export function f(x = make()) {}"
+        ),
+        2
+    );
+}
+
+#[test]
+fn inferred_param_with_some_type_args() {
+    assert_eq!(
+        type_arg_count_of_param(
+            "interface Foo<T = number, U = string> {}
+            declare function make(): Foo<boolean>;
+            export function f(x = make()) {}"
+        ),
+        2
+    );
+}
+
+#[test]
+fn inferred_param_missing_required_type_args() {
+    let info = analyze(
+        "interface Foo<T, U> {}
+        declare function make(): Foo<number, string>;
+        const g: () => Foo = make as any;
+        export function f(x = g()) {}",
+    );
+
+    let codes = info.errors.into_iter().map(|err| err.code()).filter(|&code| code == 2314).count();
+    assert_eq!(codes, 1);
+}
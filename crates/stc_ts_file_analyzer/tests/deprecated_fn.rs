@@ -0,0 +1,70 @@
+#![feature(box_syntax)]
+
+use stc_ts_file_analyzer::validator::ValidateWith;
+
+use self::common::{find_fn, run};
+
+mod common;
+
+/// Returns whether the type of `f` declared in `src` is marked as deprecated,
+/// and the message of the `@deprecated` tag.
+fn deprecation(src: &str) -> (bool, Option<String>) {
+    run(src, Default::default(), |analyzer, module| {
+        let f = find_fn(module, "f");
+        let ty = f.validate_with_args(analyzer, (None, None)).expect("function should be valid");
+        let message = analyzer.deprecation_message(&ty).map(String::from);
+
+        (ty.metadata.is_deprecated(), message)
+    })
+    .0
+}
+
+#[test]
+fn not_deprecated() {
+    assert_eq!(
+        deprecation(
+            "/** Does something. */
+            function f() {}"
+        ),
+        (false, None)
+    );
+}
+
+#[test]
+fn deprecated_without_message() {
+    assert_eq!(
+        deprecation(
+            "/** @deprecated */
+            function f() {}"
+        ),
+        (true, Some(String::new()))
+    );
+}
+
+#[test]
+fn deprecated_with_message() {
+    assert_eq!(
+        deprecation(
+            "/**
+             * Does something.
+             *
+             * @deprecated Use `g` instead,
+             * which is faster.
+             * @param x The value.
+             */
+            function f(x: number) {}"
+        ),
+        (true, Some("Use `g` instead, which is faster.".into()))
+    );
+}
+
+#[test]
+fn other_tag() {
+    assert_eq!(
+        deprecation(
+            "/** @deprecatedSince 2.0 */
+            function f() {}"
+        ),
+        (false, None)
+    );
+}
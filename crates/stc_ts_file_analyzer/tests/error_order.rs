@@ -0,0 +1,66 @@
+#![feature(box_syntax)]
+
+use stc_ts_env::Rule;
+use stc_ts_file_analyzer::validator::ValidateWith;
+
+use self::common::{find_fn, run};
+
+mod common;
+
+/// Returns the codes of errors reported while validating the function `f`
+/// declared in `src`, in the reported order.
+fn error_codes_of_fn(src: &str) -> Vec<usize> {
+    let rule = Rule {
+        strict_null_checks: true,
+        ..Default::default()
+    };
+
+    let (_, info) = run(src, rule, |analyzer, module| {
+        find_fn(module, "f")
+            .validate_with_args(analyzer, (None, None))
+            .expect("function should be valid");
+    });
+
+    info.errors.into_iter().map(|err| err.code()).collect()
+}
+
+/// `TS2355` is reported after validating the body, but the return type comes
+/// first.
+#[test]
+fn return_type_before_body() {
+    assert_eq!(
+        error_codes_of_fn(
+            "function f(): string {
+                const a: number = 'a';
+            }"
+        ),
+        vec![2355, 2322]
+    );
+}
+
+#[test]
+fn param_before_body() {
+    assert_eq!(
+        error_codes_of_fn(
+            "function f(a: number, a: number): string {
+                const b: number = 'a';
+            }"
+        ),
+        vec![2300, 2300, 2355, 2322]
+    );
+}
+
+#[test]
+fn nested_fn() {
+    assert_eq!(
+        error_codes_of_fn(
+            "function f(): string {
+                function g(): number {
+                    const a: string = 1;
+                }
+                const b: number = 'a';
+            }"
+        ),
+        vec![2355, 2355, 2322, 2322]
+    );
+}
@@ -0,0 +1,87 @@
+#![feature(box_syntax)]
+
+use stc_ts_file_analyzer::analyzer::DEFAULT_MAX_EXPAND_DEPTH;
+
+use self::common::error_codes_with;
+
+mod common;
+
+/// Mutually recursive type aliases used in a function signature.
+const RECURSIVE_ALIASES: &str = "
+type A<T> = { b: B<T> };
+type B<T> = { a: A<[T]> };
+
+declare function f(a: A<number>): void;
+
+f({ b: { a: { b: null as any } } });
+";
+
+#[test]
+fn recursive_alias_within_limit() {
+    assert!(!error_codes_with(RECURSIVE_ALIASES, Default::default(), |analyzer| analyzer.set_max_expand_depth(100)).contains(&2589));
+}
+
+#[test]
+fn recursive_alias_over_limit() {
+    assert!(error_codes_with(RECURSIVE_ALIASES, Default::default(), |analyzer| analyzer.set_max_expand_depth(1)).contains(&2589));
+}
+
+/// Returns a chain of `n` type aliases, where each one refers to the next one
+/// in a property, used in a function signature.
+fn alias_chain(n: usize) -> String {
+    let mut src = String::new();
+    for i in 0..n {
+        src.push_str(&format!("type T{} = {{ a: T{} }};\n", i, i + 1));
+    }
+    src.push_str(&format!("type T{} = number;\n", n));
+    src.push_str("declare function f(a: T0): void;\n");
+
+    let mut arg = String::from("1");
+    for _ in 0..n {
+        arg = format!("{{ a: {} }}", arg);
+    }
+    src.push_str(&format!("f({});\n", arg));
+
+    src
+}
+
+#[test]
+fn alias_chain_within_default_limit() {
+    let codes = error_codes_with(&alias_chain(20), Default::default(), |analyzer| {
+        analyzer.set_max_expand_depth(DEFAULT_MAX_EXPAND_DEPTH)
+    });
+
+    assert!(!codes.contains(&2589), "{:?}", codes);
+}
+
+#[test]
+fn alias_chain_over_limit() {
+    let codes = error_codes_with(&alias_chain(20), Default::default(), |analyzer| analyzer.set_max_expand_depth(5));
+
+    assert!(codes.contains(&2589), "{:?}", codes);
+}
+
+#[test]
+fn depth_is_restored_after_sibling_calls() {
+    let src = format!("{}{}", RECURSIVE_ALIASES, "f({ b: { a: { b: null as any } } });\n".repeat(100));
+
+    assert!(!error_codes_with(&src, Default::default(), |analyzer| analyzer.set_max_expand_depth(100)).contains(&2589));
+}
+
+#[test]
+fn nested_fns_are_not_limited() {
+    let mut src = String::from("export const f = ");
+    for _ in 0..10 {
+        src.push_str("function () { return ");
+    }
+    src.push('1');
+    for _ in 0..10 {
+        src.push_str("; }");
+    }
+    src.push(';');
+
+    assert_eq!(
+        error_codes_with(&src, Default::default(), |analyzer| analyzer.set_max_expand_depth(1)),
+        Vec::<usize>::new()
+    );
+}
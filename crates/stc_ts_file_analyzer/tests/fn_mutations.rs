@@ -0,0 +1,58 @@
+#![feature(box_syntax)]
+
+use stc_ts_file_analyzer::validator::ValidateWith;
+use stc_ts_types::Type;
+use swc_ecma_ast::TsKeywordTypeKind;
+
+use self::common::{find_fn, run};
+
+mod common;
+
+/// Returns the return type of the function `f` recorded for `.d.ts` files.
+fn recorded_ret_ty(src: &str) -> Option<Type> {
+    run(src, Default::default(), |analyzer, module| {
+        module.validate_with(analyzer).unwrap();
+
+        let node_id = find_fn(module, "f").node_id;
+        let mutations = analyzer.mutations.take().unwrap();
+
+        mutations.for_fns.get(&node_id).and_then(|m| m.ret_ty.clone())
+    })
+    .0
+}
+
+#[test]
+fn empty_body() {
+    let ty = recorded_ret_ty("function f() {}").expect("return type should be recorded");
+
+    assert!(ty.is_kwd(TsKeywordTypeKind::TsVoidKeyword), "{:?}", ty);
+}
+
+#[test]
+fn body_without_return() {
+    let ty = recorded_ret_ty(
+        "function f() {
+            const a = 1;
+        }",
+    )
+    .expect("return type should be recorded");
+
+    assert!(ty.is_kwd(TsKeywordTypeKind::TsVoidKeyword), "{:?}", ty);
+}
+
+#[test]
+fn inferred() {
+    let ty = recorded_ret_ty(
+        "function f() {
+            return 1;
+        }",
+    )
+    .expect("return type should be recorded");
+
+    assert!(ty.is_kwd(TsKeywordTypeKind::TsNumberKeyword), "{:?}", ty);
+}
+
+#[test]
+fn annotated() {
+    assert!(recorded_ret_ty("function f(): void {}").is_none());
+}
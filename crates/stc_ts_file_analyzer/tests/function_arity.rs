@@ -0,0 +1,43 @@
+#![feature(box_syntax)]
+
+use stc_ts_env::Rule;
+use stc_ts_file_analyzer::validator::ValidateWith;
+
+use self::common::{find_fn, run};
+
+mod common;
+
+/// Returns the arity of the function `f` declared in `src`.
+fn arity(src: &str) -> (usize, Option<usize>) {
+    let rule = Rule {
+        strict_null_checks: true,
+        ..Default::default()
+    };
+
+    run(src, rule, |analyzer, module| {
+        let f = find_fn(module, "f")
+            .validate_with_args(analyzer, (None, None))
+            .expect("function should be valid");
+
+        analyzer.function_arity(&f)
+    })
+    .0
+}
+
+#[test]
+fn required_optional_and_rest() {
+    assert_eq!(
+        arity("declare function f(a: number, b?: string, ...c: boolean[]): void;"),
+        (1, None)
+    );
+}
+
+#[test]
+fn required_and_optional() {
+    assert_eq!(arity("function f(a: number, b?: string, c = 1) {}"), (1, Some(3)));
+}
+
+#[test]
+fn only_rest() {
+    assert_eq!(arity("declare function f(...args: number[]): void;"), (0, None));
+}
@@ -0,0 +1,148 @@
+#![feature(box_syntax)]
+
+use stc_ts_env::Rule;
+use stc_ts_file_analyzer::validator::ValidateWith;
+
+use self::common::{find_fn, run};
+
+mod common;
+
+fn rule(strict_function_types: bool) -> Rule {
+    Rule {
+        strict_function_types,
+        ..Default::default()
+    }
+}
+
+/// Returns whether the signature of `source` declared in `src` is compatible
+/// with the signature of `target` under `rule`.
+///
+/// The module itself is validated with the default rule, so `rule` is used
+/// only by the comparison.
+fn is_compatible(src: &str, rule: Rule) -> bool {
+    run(src, Default::default(), |analyzer, module| {
+        module.validate_with(analyzer).expect("module should be valid");
+
+        let source = find_fn(module, "source")
+            .validate_with_args(analyzer, (None, None))
+            .expect("source signature should be valid");
+        let target = find_fn(module, "target")
+            .validate_with_args(analyzer, (None, None))
+            .expect("target signature should be valid");
+
+        analyzer.is_fn_compatible_with(&source, &target, rule)
+    })
+    .0
+}
+
+#[test]
+fn same_signature() {
+    assert!(is_compatible(
+        "declare function source(a: number): string;
+        declare function target(a: number): string;",
+        rule(true)
+    ));
+}
+
+#[test]
+fn contravariant_params() {
+    let src = "declare function source(a: string | number): void;
+        declare function target(a: string): void;";
+
+    assert!(is_compatible(src, rule(true)));
+}
+
+#[test]
+fn covariant_params_with_strict_function_types() {
+    let src = "declare function source(a: string): void;
+        declare function target(a: string | number): void;";
+
+    assert!(!is_compatible(src, rule(true)));
+}
+
+#[test]
+fn bivariant_params_without_strict_function_types() {
+    let src = "declare function source(a: string): void;
+        declare function target(a: string | number): void;";
+
+    assert!(is_compatible(src, rule(false)));
+}
+
+#[test]
+fn bivariant_method_params() {
+    let src = "declare function source(m: { f(a: string): void }): void;
+        declare function target(m: { f(a: string | number): void }): void;";
+
+    assert!(is_compatible(src, rule(true)));
+}
+
+#[test]
+fn covariant_return_type() {
+    assert!(is_compatible(
+        "declare function source(): string;
+        declare function target(): string | number;",
+        rule(true)
+    ));
+    assert!(!is_compatible(
+        "declare function source(): string | number;
+        declare function target(): string;",
+        rule(true)
+    ));
+}
+
+#[test]
+fn fewer_params() {
+    assert!(is_compatible(
+        "declare function source(a: number): void;
+        declare function target(a: number, b: string): void;",
+        rule(true)
+    ));
+}
+
+#[test]
+fn more_required_params() {
+    assert!(!is_compatible(
+        "declare function source(a: number, b: string): void;
+        declare function target(a: number): void;",
+        rule(true)
+    ));
+}
+
+#[test]
+fn optional_and_rest_params() {
+    assert!(is_compatible(
+        "declare function source(a: number, b?: string): void;
+        declare function target(a: number): void;",
+        rule(true)
+    ));
+    assert!(is_compatible(
+        "declare function source(...args: number[]): void;
+        declare function target(a: number, b: number): void;",
+        rule(true)
+    ));
+}
+
+#[test]
+fn declared_interface() {
+    let src = "interface Animal {
+            name: string;
+        }
+        interface Dog extends Animal {
+            bark(): void;
+        }
+        declare function source(a: Animal): Dog;
+        declare function target(a: Dog): Animal;";
+
+    assert!(is_compatible(src, rule(true)));
+
+    let src = "interface Animal {
+            name: string;
+        }
+        interface Dog extends Animal {
+            bark(): void;
+        }
+        declare function source(a: Dog): void;
+        declare function target(a: Animal): void;";
+
+    assert!(!is_compatible(src, rule(true)));
+}
@@ -0,0 +1,100 @@
+#![feature(box_syntax)]
+
+use stc_ts_env::Rule;
+
+mod common;
+
+/// Returns the error codes reported while validating `src`.
+fn error_codes(src: &str) -> Vec<usize> {
+    common::error_codes(
+        src,
+        Rule {
+            use_jsdoc_types: true,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn overloads() {
+    assert_eq!(
+        error_codes(
+            "/**
+             * @overload
+             * @param {string} x
+             * @returns {string}
+             */
+            /**
+             * @overload
+             * @param {number} x
+             * @returns {number}
+             */
+            /**
+             * @param {string | number} x
+             * @returns {string | number}
+             */
+            function f(x) {
+                return x;
+            }
+
+            const a: string = f('');
+            const b: number = f(1);
+            const c: number = f('');"
+        ),
+        vec![2322]
+    );
+}
+
+#[test]
+fn overloads_in_one_comment() {
+    assert_eq!(
+        error_codes(
+            "/**
+             * @overload
+             * @param {string} x
+             * @returns {string}
+             *
+             * @overload
+             * @param {number} x
+             * @returns {number}
+             */
+            /**
+             * @param {string | number} x
+             * @returns {string | number}
+             */
+            function f(x) {
+                return x;
+            }
+
+            const a: string = f('');
+            const b: number = f(1);"
+        ),
+        vec![]
+    );
+}
+
+#[test]
+fn incompatible_overload() {
+    assert_eq!(
+        error_codes(
+            "/**
+             * @overload
+             * @param {string} x
+             * @returns {string}
+             */
+            /**
+             * @overload
+             * @param {boolean} x
+             * @returns {boolean}
+             */
+            /**
+             * @param {string | number} x
+             * @returns {string | number}
+             */
+            function f(x) {
+                return x;
+            }"
+        ),
+        vec![2394]
+    );
+}
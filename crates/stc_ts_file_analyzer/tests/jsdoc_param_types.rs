@@ -0,0 +1,94 @@
+#![feature(box_syntax)]
+
+use stc_ts_env::Rule;
+
+mod common;
+
+/// Returns the error codes reported while validating `src`.
+fn error_codes(src: &str) -> Vec<usize> {
+    common::error_codes(
+        src,
+        Rule {
+            use_jsdoc_types: true,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn param_tag() {
+    assert_eq!(
+        error_codes(
+            "/**
+             * @param {string} a The first one.
+             * @param {number} b
+             */
+            function f(a, b) {
+                const x: number = a;
+                const y: number = b;
+            }"
+        ),
+        vec![2322]
+    );
+}
+
+#[test]
+fn optional_param_tag() {
+    assert_eq!(
+        error_codes(
+            "/** @param {number} [a] */
+            function f(a) {}
+            f();"
+        ),
+        vec![]
+    );
+}
+
+#[test]
+fn required_param_tag() {
+    assert_eq!(
+        error_codes(
+            "/** @param {number} a */
+            function f(a) {}
+            f();"
+        ),
+        vec![2554]
+    );
+}
+
+#[test]
+fn param_tag_of_destructuring_pattern() {
+    assert_eq!(
+        error_codes(
+            "/** @param {{ a: string }} opts */
+            function f({ a }) {
+                const x: number = a;
+            }"
+        ),
+        vec![2322]
+    );
+}
+
+#[test]
+fn type_annotation_wins_over_param_tag() {
+    assert_eq!(
+        error_codes(
+            "/** @param {string} a */
+            function f(a: number) {
+                const x: number = a;
+            }"
+        ),
+        vec![]
+    );
+}
+
+#[test]
+fn param_tag_with_unknown_name() {
+    assert_eq!(
+        error_codes(
+            "/** @param {number} b */
+            function f(a) {}"
+        ),
+        vec![8024]
+    );
+}
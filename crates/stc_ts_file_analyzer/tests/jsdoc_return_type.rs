@@ -0,0 +1,88 @@
+#![feature(box_syntax)]
+
+use stc_ts_env::Rule;
+
+mod common;
+
+/// Returns the error codes reported while validating `src`.
+fn error_codes(src: &str) -> Vec<usize> {
+    common::error_codes(
+        src,
+        Rule {
+            use_jsdoc_types: true,
+            ..Default::default()
+        },
+    )
+}
+
+#[test]
+fn returns_tag() {
+    assert_eq!(
+        error_codes(
+            "/** @returns {number} */
+            function f() {
+                return 'foo';
+            }"
+        ),
+        vec![2322]
+    );
+}
+
+#[test]
+fn return_tag() {
+    assert_eq!(
+        error_codes(
+            "/**
+             * @return {string} The name.
+             */
+            function f() {
+                return 1;
+            }"
+        ),
+        vec![2322]
+    );
+}
+
+#[test]
+fn returns_tag_used_as_return_type() {
+    assert_eq!(
+        error_codes(
+            "/** @returns {number} */
+            function f(): any {
+                return 1;
+            }
+            /** @returns {string} */
+            function g() {
+                return f();
+            }
+            const n: number = g();"
+        ),
+        vec![2322]
+    );
+}
+
+#[test]
+fn malformed_tag_is_ignored() {
+    assert_eq!(
+        error_codes(
+            "/** @returns {number */
+            function f() {
+                return 'foo';
+            }"
+        ),
+        vec![]
+    );
+}
+
+#[test]
+fn unresolvable_type_is_ignored() {
+    assert_eq!(
+        error_codes(
+            "/** @returns {Foo} */
+            function f() {
+                return 'foo';
+            }"
+        ),
+        vec![]
+    );
+}
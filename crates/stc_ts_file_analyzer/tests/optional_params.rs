@@ -0,0 +1,83 @@
+#![feature(box_syntax)]
+
+use stc_ts_env::Rule;
+use stc_ts_file_analyzer::validator::ValidateWith;
+use stc_ts_types::Function;
+use swc_ecma_ast::TsKeywordTypeKind;
+
+use self::common::{find_fn, run};
+
+mod common;
+
+/// Returns the type of the function `f` declared in `src`.
+fn type_of_fn(src: &str) -> Function {
+    let rule = Rule {
+        strict_null_checks: true,
+        ..Default::default()
+    };
+
+    run(src, rule, |analyzer, module| {
+        find_fn(module, "f")
+            .validate_with_args(analyzer, (None, None))
+            .expect("function should be valid")
+    })
+    .0
+}
+
+/// Optionality is stored in [stc_ts_types::FnParam::required], so the type of
+/// the parameter itself should not contain `undefined`.
+fn assert_optional_param_without_undefined(src: &str) {
+    let f = type_of_fn(src);
+
+    let param = &f.params[0];
+    assert!(!param.required, "parameter should be optional");
+    assert!(
+        !param.ty.iter_union().any(|ty| ty.is_kwd(TsKeywordTypeKind::TsUndefinedKeyword)),
+        "type of an optional parameter should not contain `undefined`: {:?}",
+        param.ty
+    );
+}
+
+#[test]
+fn optional_param() {
+    assert_optional_param_without_undefined("function f(a?: number) {}");
+}
+
+#[test]
+fn optional_param_with_union() {
+    assert_optional_param_without_undefined("function f(a?: number | string) {}");
+}
+
+#[test]
+fn param_with_default_value() {
+    assert_optional_param_without_undefined("function f(a = 1) {}");
+}
+
+/// Returns `required` of each parameter of the function `f` declared in
+/// `src`.
+fn requiredness_of_params(src: &str) -> Vec<bool> {
+    type_of_fn(src).params.iter().map(|param| param.required).collect()
+}
+
+#[test]
+fn no_params() {
+    assert_eq!(requiredness_of_params("function f() {}"), vec![]);
+}
+
+#[test]
+fn only_rest_param() {
+    assert_eq!(requiredness_of_params("function f(...a: any[]) {}"), vec![false]);
+}
+
+#[test]
+fn only_optional_param() {
+    assert_eq!(requiredness_of_params("function f(a?: number) {}"), vec![false]);
+}
+
+#[test]
+fn required_param_after_rest_and_optional_params() {
+    assert_eq!(
+        requiredness_of_params("function f(a: number, b?: number, ...c: number[]) {}"),
+        vec![true, false, false]
+    );
+}
@@ -0,0 +1,85 @@
+#![feature(box_syntax)]
+
+use stc_ts_file_analyzer::validator::ValidateWith;
+use stc_ts_storage::Info;
+use stc_ts_types::Type;
+
+use self::common::run;
+
+mod common;
+
+/// Validates `src` and returns the exports and the reported errors.
+fn analyze(src: &str) -> Info {
+    run(src, Default::default(), |analyzer, module| {
+        module.validate_with(analyzer).expect("module should be valid");
+    })
+    .1
+}
+
+/// Returns the number of call signatures of the exported function `f`
+/// declared in `src`.
+fn signature_count(src: &str) -> usize {
+    let info = analyze(src);
+
+    let f = info.exports.vars.get(&"f".into()).expect("`f` should be exported");
+    f.iter_union()
+        .inspect(|ty| assert!(matches!(ty.normalize(), Type::Function(..)), "expected a function, got {:?}", ty))
+        .count()
+}
+
+#[test]
+fn distinct_overloads() {
+    assert_eq!(
+        signature_count(
+            "export function f(a: string): string;
+            export function f(a: number): number;
+            export function f(a: any) {
+                return a;
+            }"
+        ),
+        2
+    );
+}
+
+#[test]
+fn overloads_with_different_param_names() {
+    assert_eq!(
+        signature_count(
+            "export function f(a: string): string;
+            export function f(b: string): string;
+            export function f(a: number): number;
+            export function f(a: any) {
+                return a;
+            }"
+        ),
+        2
+    );
+}
+
+#[test]
+fn overloads_with_different_type_param_names() {
+    assert_eq!(
+        signature_count(
+            "export function f<T extends string>(a: T): T;
+            export function f<U extends string>(b: U): U;
+            export function f(a: any) {
+                return a;
+            }"
+        ),
+        1
+    );
+}
+
+#[test]
+fn overloads_with_different_constraints() {
+    assert_eq!(
+        signature_count(
+            "export function f<T extends string>(a: T): T;
+            export function f<T extends number>(a: T): T;
+            export function f(a: any) {
+                return a;
+            }"
+        ),
+        2
+    );
+}
@@ -71,3 +71,9 @@ fn profile_file(name: &str, path: &Path) {
 fn profile_csstype() {
     profile_file("csstype", &PathBuf::new().join("node_modules").join("csstype").join("index.d.ts"));
 }
+
+#[test]
+#[ignore = "Not a test"]
+fn profile_reused_fns() {
+    profile_file("reused_fns", &PathBuf::new().join("tests").join("perf").join("reusedFns.ts"));
+}
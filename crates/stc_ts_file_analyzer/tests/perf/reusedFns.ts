@@ -0,0 +1,252 @@
+// Functions used before their declarations are validated more than once.
+
+export const v0 = f0({ a: 0, b: "0" });
+export const v1 = f1({ a: 1, b: "1" });
+export const v2 = f2({ a: 2, b: "2" });
+export const v3 = f3({ a: 3, b: "3" });
+export const v4 = f4({ a: 4, b: "4" });
+export const v5 = f5({ a: 5, b: "5" });
+export const v6 = f6({ a: 6, b: "6" });
+export const v7 = f7({ a: 7, b: "7" });
+export const v8 = f8({ a: 8, b: "8" });
+export const v9 = f9({ a: 9, b: "9" });
+export const v10 = f10({ a: 10, b: "10" });
+export const v11 = f11({ a: 11, b: "11" });
+export const v12 = f12({ a: 12, b: "12" });
+export const v13 = f13({ a: 13, b: "13" });
+export const v14 = f14({ a: 14, b: "14" });
+export const v15 = f15({ a: 15, b: "15" });
+export const v16 = f16({ a: 16, b: "16" });
+export const v17 = f17({ a: 17, b: "17" });
+export const v18 = f18({ a: 18, b: "18" });
+export const v19 = f19({ a: 19, b: "19" });
+export const v20 = f20({ a: 20, b: "20" });
+export const v21 = f21({ a: 21, b: "21" });
+export const v22 = f22({ a: 22, b: "22" });
+export const v23 = f23({ a: 23, b: "23" });
+export const v24 = f24({ a: 24, b: "24" });
+export const v25 = f25({ a: 25, b: "25" });
+export const v26 = f26({ a: 26, b: "26" });
+export const v27 = f27({ a: 27, b: "27" });
+export const v28 = f28({ a: 28, b: "28" });
+export const v29 = f29({ a: 29, b: "29" });
+export const v30 = f30({ a: 30, b: "30" });
+export const v31 = f31({ a: 31, b: "31" });
+export const v32 = f32({ a: 32, b: "32" });
+export const v33 = f33({ a: 33, b: "33" });
+export const v34 = f34({ a: 34, b: "34" });
+export const v35 = f35({ a: 35, b: "35" });
+export const v36 = f36({ a: 36, b: "36" });
+export const v37 = f37({ a: 37, b: "37" });
+export const v38 = f38({ a: 38, b: "38" });
+export const v39 = f39({ a: 39, b: "39" });
+export const v40 = f40({ a: 40, b: "40" });
+export const v41 = f41({ a: 41, b: "41" });
+export const v42 = f42({ a: 42, b: "42" });
+export const v43 = f43({ a: 43, b: "43" });
+export const v44 = f44({ a: 44, b: "44" });
+export const v45 = f45({ a: 45, b: "45" });
+export const v46 = f46({ a: 46, b: "46" });
+export const v47 = f47({ a: 47, b: "47" });
+export const v48 = f48({ a: 48, b: "48" });
+export const v49 = f49({ a: 49, b: "49" });
+
+function f0<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f1<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f2<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f3<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f4<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f5<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f6<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f7<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f8<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f9<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f10<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f11<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f12<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f13<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f14<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f15<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f16<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f17<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f18<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f19<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f20<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f21<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f22<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f23<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f24<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f25<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f26<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f27<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f28<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f29<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f30<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f31<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f32<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f33<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f34<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f35<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f36<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f37<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f38<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f39<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f40<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f41<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f42<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f43<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f44<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f45<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f46<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f47<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f48<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
+
+function f49<T extends { a: number; b: string }>(v: T): Pick<T, "a" | "b"> {
+    return { a: v.a, b: v.b };
+}
@@ -0,0 +1,5 @@
+function f() {
+    const a: IArguments = arguments;
+    const n: number = arguments.length;
+    const s: string = arguments;
+}
@@ -0,0 +1,15 @@
+function f() {
+    const g = () => {
+        const a: IArguments = arguments;
+    };
+}
+
+class C {
+    constructor() {
+        const a: IArguments = arguments;
+    }
+
+    method() {
+        const a: IArguments = arguments;
+    }
+}
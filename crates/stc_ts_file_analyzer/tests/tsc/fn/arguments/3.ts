@@ -0,0 +1,3 @@
+const a = arguments;
+
+const f = () => arguments;
@@ -0,0 +1,23 @@
+function f() {
+    return class {
+        a = arguments;
+        b = () => arguments;
+        c = function () {
+            return arguments;
+        };
+
+        method() {
+            return () => arguments;
+        }
+    };
+}
+
+function g() {
+    return class {
+        static a = () => arguments.length;
+
+        static {
+            const b = arguments;
+        }
+    };
+}
@@ -0,0 +1,12 @@
+// @strict: true
+
+function assertIsString(x: unknown): asserts x is string {
+    if (typeof x !== "string") {
+        throw new Error("not a string");
+    }
+}
+
+declare const value: unknown;
+
+assertIsString(value);
+const len: number = value.length;
@@ -0,0 +1,8 @@
+// @strict: true
+
+declare function assertDefined(x: unknown): asserts x;
+
+declare const value: string | undefined;
+
+assertDefined(value);
+const s: string = value;
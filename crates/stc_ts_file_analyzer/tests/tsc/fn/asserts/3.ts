@@ -0,0 +1,8 @@
+// @strict: true
+
+declare function assert(condition: unknown): asserts condition;
+
+declare const value: string | number;
+
+assert(typeof value === "string");
+const s: string = value;
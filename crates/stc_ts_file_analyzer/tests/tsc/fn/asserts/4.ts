@@ -0,0 +1,7 @@
+function assertTruthy(x: unknown): asserts x {
+    return 1;
+}
+
+declare class Box {
+    get ok(): asserts this is Box;
+}
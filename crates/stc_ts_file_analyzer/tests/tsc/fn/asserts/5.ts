@@ -0,0 +1,8 @@
+const assertIsString: (x: unknown) => asserts x is string = (x) => {
+    if (typeof x !== "string") throw new Error();
+};
+
+export function f(x: unknown) {
+    assertIsString(x);
+    return x.length;
+}
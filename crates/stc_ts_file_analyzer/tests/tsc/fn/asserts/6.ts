@@ -0,0 +1,7 @@
+const assertIsString = (x: unknown): asserts x is string => {
+    if (typeof x !== "string") throw new Error();
+};
+
+export function f(x: unknown) {
+    assertIsString(x);
+}
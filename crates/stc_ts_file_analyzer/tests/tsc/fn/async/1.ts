@@ -0,0 +1,6 @@
+async function f() {
+    return 1;
+}
+
+export const a: Promise<number> = f();
+export const b: Promise<string> = f();
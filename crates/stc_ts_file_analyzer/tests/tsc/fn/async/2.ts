@@ -0,0 +1,9 @@
+async function f() {
+    return Promise.resolve(1);
+}
+
+export const a: Promise<number> = f();
+
+const g = async () => Promise.resolve("a");
+
+export const b: Promise<string> = g();
@@ -0,0 +1,9 @@
+async function f(): Promise<number> {
+    return 1;
+}
+
+async function g(): Promise<number> {
+    return "a";
+}
+
+export const h = async (): Promise<number> => 1;
@@ -0,0 +1,10 @@
+async function f() {
+    return 1;
+}
+
+type P = Promise<Promise<string>>;
+
+export const a: Awaited<ReturnType<typeof f>> = 1;
+export const b: Awaited<ReturnType<typeof f>> = "";
+export const c: Awaited<P> = "";
+export const d: Awaited<P> = 1;
@@ -0,0 +1,9 @@
+type P = Promise<number>;
+
+async function f(): Promise<number> {
+    return 1;
+}
+
+async function g(): P {
+    return 1;
+}
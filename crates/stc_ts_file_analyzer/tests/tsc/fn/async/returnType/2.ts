@@ -0,0 +1,8 @@
+class Thenable {
+    then(cb: (value: number) => void) {}
+}
+
+// Only the global `Promise<T>` is allowed with es2015 or later.
+async function f(): Thenable {
+    return 1;
+}
@@ -0,0 +1,5 @@
+async function f(): number {
+    return 1;
+}
+
+const g = async (): string => "a";
@@ -0,0 +1,7 @@
+const f = async (): number => 1;
+
+const g = async (): Promise<number> => 1;
+
+const h = async (x: number): Promise<number> => {
+    return x;
+};
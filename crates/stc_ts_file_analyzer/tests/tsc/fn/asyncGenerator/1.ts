@@ -0,0 +1,15 @@
+export async function* numbers() {
+    yield Promise.resolve(1);
+    yield 2;
+}
+
+export async function sum() {
+    let total = 0;
+    for await (const n of numbers()) {
+        total += n;
+    }
+    const s: string = total;
+    return total;
+}
+
+export const g: AsyncGenerator<number, void, unknown> = numbers();
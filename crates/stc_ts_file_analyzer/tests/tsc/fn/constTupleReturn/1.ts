@@ -0,0 +1,8 @@
+// @strictNullChecks: true
+export function f() {
+    return [1, undefined] as const;
+}
+
+export const r: readonly [1, undefined] = f();
+export const first: 2 = f()[0];
+export const second: string = f()[1];
@@ -0,0 +1,7 @@
+declare function f<const T>(x: T): T;
+
+const a = f(["a", 1]);
+export const b: readonly ["a", 1] = a;
+
+const c = f({ x: 1, y: "y" });
+export const d: { readonly x: 1; readonly y: "y" } = c;
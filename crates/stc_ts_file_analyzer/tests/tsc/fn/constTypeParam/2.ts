@@ -0,0 +1,7 @@
+declare function g<T>(x: T): T;
+
+const a = g(["a", 1]);
+export const b: readonly ["a", 1] = a;
+
+const c = g({ x: 1 });
+export const d: { x: 1 } = c;
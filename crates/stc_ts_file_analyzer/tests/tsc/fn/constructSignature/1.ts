@@ -0,0 +1,7 @@
+class Foo {}
+
+declare let fn: (x: number) => Foo;
+declare let ctor: new (x: number) => Foo;
+
+fn = ctor;
+ctor = fn;
@@ -0,0 +1,11 @@
+class Foo {}
+
+declare let fn: (x: number) => Foo;
+declare let ctor: new (x: number) => Foo;
+declare let lit: { new (x: number): Foo };
+
+ctor = lit;
+ctor = Foo;
+
+fn = lit;
+fn = Foo;
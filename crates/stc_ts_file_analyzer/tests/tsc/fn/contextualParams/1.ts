@@ -0,0 +1,7 @@
+// @noImplicitAny: true
+
+const arr = [1, 2, 3];
+
+export const a: string[] = arr.map((x, i) => x.toFixed(i));
+export const b: number[] = arr.map((x, i) => x + i);
+export const c: string[] = arr.map((x) => x);
@@ -0,0 +1,6 @@
+// @noImplicitAny: true
+
+[1, 2].forEach(function (x, i) {
+    const s: string = x;
+    const n: number = i;
+});
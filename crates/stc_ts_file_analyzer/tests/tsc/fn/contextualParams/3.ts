@@ -0,0 +1,7 @@
+// @noImplicitAny: true
+
+export const f: (...a: [number, string]) => void = (x, y) => {
+    const n: number = x;
+    const s: string = y;
+    const e: string = x;
+};
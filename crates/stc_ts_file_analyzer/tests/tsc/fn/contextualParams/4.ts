@@ -0,0 +1,15 @@
+// @noImplicitAny: true
+
+type Args = [boolean, ...number[]];
+
+export const f: (...a: Args) => void = (x, y, z) => {
+    const b: boolean = x;
+    const n: number = y;
+    const e: boolean = z;
+};
+
+export const g: (a: string, ...rest: [number]) => void = (x, y) => {
+    const s: string = x;
+    const n: number = y;
+    const e: string = y;
+};
@@ -0,0 +1,12 @@
+type Dir = "left" | "right";
+
+export const f: (flag: boolean) => Dir = function (flag) {
+    if (flag) {
+        return "left";
+    }
+    return "right";
+};
+
+export const g: (flag: boolean) => Dir = (flag) => {
+    return flag ? "left" : "right";
+};
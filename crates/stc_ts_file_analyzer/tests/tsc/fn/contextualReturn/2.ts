@@ -0,0 +1,7 @@
+export const f: () => { kind: "a" | "b" } = function () {
+    return { kind: "a" };
+};
+
+export const g: () => number = function () {
+    return "x";
+};
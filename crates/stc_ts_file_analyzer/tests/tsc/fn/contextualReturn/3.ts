@@ -0,0 +1,11 @@
+const f = function () {
+    return 1;
+} satisfies () => number;
+
+export const a: 1 = f();
+
+const g = function () {
+    return "a";
+} satisfies () => "a" | "b";
+
+export const b: "a" = g();
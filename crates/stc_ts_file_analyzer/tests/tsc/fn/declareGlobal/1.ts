@@ -0,0 +1,8 @@
+export {};
+
+declare global {
+    function globalFn(): void;
+}
+
+globalFn();
+export const a: number = globalFn();
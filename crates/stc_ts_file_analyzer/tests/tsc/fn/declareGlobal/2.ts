@@ -0,0 +1,10 @@
+export {};
+
+declare global {
+    function parse(x: string): number;
+    function parse(x: number): string;
+}
+
+export const a: number = parse("");
+export const b: string = parse(1);
+export const c: string = parse("");
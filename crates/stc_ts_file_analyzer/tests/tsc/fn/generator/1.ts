@@ -0,0 +1,4 @@
+export function* empty() {}
+
+export const g1: Generator<never, void, unknown> = empty();
+export const value: number = empty().next().value;
@@ -0,0 +1,11 @@
+function* inner() {
+    yield 1;
+    return "done";
+}
+
+export function* outer() {
+    yield* inner();
+    yield "a";
+}
+
+export const g: Generator<number | string, void, unknown> = outer();
@@ -0,0 +1,6 @@
+function f<T>() {
+    return (x: T) => x;
+}
+
+export const a: (x: number) => number = f<number>();
+export const b: (x: number) => string = f<number>();
@@ -0,0 +1,5 @@
+function f<T>(a: T) {
+    return <T>(x: T) => x;
+}
+
+export const a: string = f(1)("a");
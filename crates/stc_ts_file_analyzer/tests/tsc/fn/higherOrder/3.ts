@@ -0,0 +1,6 @@
+function f<T>(v: T) {
+    return () => () => v;
+}
+
+export const a: number = f(1)()();
+export const b: string = f(1)()();
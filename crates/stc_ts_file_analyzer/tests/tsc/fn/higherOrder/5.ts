@@ -0,0 +1,8 @@
+function f<A>(a: A) {
+    return function <B>(b: B) {
+        return [a, b];
+    };
+}
+
+export const a: (number | string)[] = f(1)("a");
+export const b: [number, string] = f(1)("a");
@@ -0,0 +1,6 @@
+const x = (function () {
+    return 1;
+})();
+
+export const a: number = x;
+export const b: string = x;
@@ -0,0 +1,6 @@
+const x = (async function () {
+    return 1;
+})();
+
+export const a: Promise<number> = x;
+export const b: number = x;
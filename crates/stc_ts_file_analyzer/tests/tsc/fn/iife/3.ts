@@ -0,0 +1,7 @@
+const x = (() => "foo")();
+const y = (async () => "foo")();
+
+export const a: string = x;
+export const b: Promise<string> = y;
+export const c: number = x;
+export const d: Promise<number> = y;
@@ -0,0 +1,4 @@
+// @noImplicitAny: true
+export function f(a, { b, c = 1 }, [d, e]) {}
+
+export const g: (x: number, p: { y: string }) => void = (x, { y }) => {};
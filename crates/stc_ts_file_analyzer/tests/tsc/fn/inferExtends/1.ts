@@ -0,0 +1,15 @@
+// @strict: true
+
+type FirstString<T> = T extends [infer U extends string, ...unknown[]] ? U : never;
+
+declare function first<T extends unknown[]>(...args: T): FirstString<T>;
+
+function str(): FirstString<["a", 1]> {
+    return "a";
+}
+
+function num(): FirstString<[1, "a"]> {
+    return 1;
+}
+
+const a: "a" = first("a" as const, 1);
@@ -0,0 +1,4 @@
+declare function elem<T>(): T extends Array<infer U> ? U : never;
+
+export const a: number = elem<number[]>();
+export const b: string = elem<number[]>();
@@ -0,0 +1,4 @@
+declare function elem<T>(): T extends Array<infer U> ? U : boolean;
+
+export const a: boolean = elem<string>();
+export const b: number = elem<string>();
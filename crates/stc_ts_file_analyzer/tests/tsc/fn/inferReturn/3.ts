@@ -0,0 +1,33 @@
+// @strict: true
+
+function tryCatch() {
+    try {
+        return 1;
+    } catch {
+        return "a";
+    }
+}
+
+function withFinally() {
+    try {
+        return 1;
+    } catch {
+        return "a";
+    } finally {
+        return true;
+    }
+}
+
+function rethrow() {
+    try {
+        return 1;
+    } catch (e) {
+        throw e;
+    }
+}
+
+const a: number | string = tryCatch();
+const b: number = tryCatch();
+const c: boolean = withFinally();
+const d: number = withFinally();
+const e: number = rethrow();
@@ -0,0 +1,6 @@
+type F = ((a: string) => string) & ((a: number) => number);
+
+export const f: F = (a: string) => a;
+export const g: F = function (a: number) {
+    return a;
+};
@@ -0,0 +1,20 @@
+function str() {
+    return "a";
+}
+
+function num() {
+    return 1;
+}
+
+function bool() {
+    return true;
+}
+
+let s = str();
+s = "b";
+
+let n = num();
+n = 2;
+
+let b = bool();
+b = false;
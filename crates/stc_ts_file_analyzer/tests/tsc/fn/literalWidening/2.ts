@@ -0,0 +1,10 @@
+function f(x: boolean) {
+    if (x) {
+        return "a";
+    }
+    return 1;
+}
+
+let v = f(true);
+v = "b";
+v = 2;
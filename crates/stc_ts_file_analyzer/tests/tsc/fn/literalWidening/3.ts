@@ -0,0 +1,13 @@
+function f() {
+    return "a" as const;
+}
+
+function g(): "a" {
+    return "a";
+}
+
+let v = f();
+v = "b";
+
+let w = g();
+w = "b";
@@ -0,0 +1,13 @@
+enum E {
+    A = "a",
+    B = "b",
+}
+
+function f() {
+    return E.A;
+}
+
+const e: E = f();
+
+let v = f();
+v = E.B;
@@ -0,0 +1,11 @@
+export function outer(
+    cb = function inner(n: number): number {
+        return n > 0 ? inner(n - 1) : 0;
+    }
+) {
+    return cb(1);
+}
+
+export const f = function a(x = function b() {}) {
+    return x;
+};
@@ -0,0 +1,11 @@
+export function f(): never {
+    throw new Error();
+}
+
+export function fail(message: string): never {
+    throw new Error(message);
+}
+
+export function g(): never {
+    fail("g");
+}
@@ -0,0 +1,16 @@
+declare function fail(message: string): never;
+
+const f = function (flag: boolean) {
+    if (flag) {
+        throw new Error();
+    } else {
+        fail("f");
+    }
+};
+
+const g = () => {
+    fail("g");
+};
+
+export const a: never = f(true);
+export const b: string = g();
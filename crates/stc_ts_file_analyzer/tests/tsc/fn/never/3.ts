@@ -0,0 +1,12 @@
+function fail(message: string) {
+    throw new Error(message);
+}
+
+class C {
+    m() {
+        throw new Error();
+    }
+}
+
+export const a: never = fail("a");
+export const b: never = new C().m();
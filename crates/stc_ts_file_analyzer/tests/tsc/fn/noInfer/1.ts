@@ -0,0 +1,4 @@
+declare function createStreetLight<C extends string>(colors: C[], defaultColor?: NoInfer<C>): C;
+
+export const a: "red" | "yellow" | "green" = createStreetLight(["red", "yellow", "green"], "red");
+createStreetLight(["red", "yellow", "green"], "blue");
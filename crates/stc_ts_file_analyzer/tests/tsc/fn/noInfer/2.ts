@@ -0,0 +1,4 @@
+declare function pick<T>(fallback: NoInfer<T>, values: T[]): T;
+
+export const a: number = pick(0, [1, 2]);
+pick("", [1, 2]);
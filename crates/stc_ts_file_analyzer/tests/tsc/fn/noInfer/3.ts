@@ -0,0 +1,4 @@
+declare function only<T>(value: NoInfer<T>): T;
+
+export const a: unknown = only("");
+export const b: string = only("");
@@ -0,0 +1,8 @@
+// @strict: true
+
+function f(o?: { x: number }) {
+    return o?.x;
+}
+
+export const a: number | undefined = f();
+export const b: number = f({ x: 1 });
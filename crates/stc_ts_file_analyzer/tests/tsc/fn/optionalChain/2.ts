@@ -0,0 +1,14 @@
+// @strict: true
+
+function f(o?: { a?: { b: string } }) {
+    return o?.a?.b;
+}
+
+function g(o?: { a: { b: string } }) {
+    return o?.a.b;
+}
+
+export const a: string | undefined = f();
+export const b: string = f({ a: { b: "" } });
+export const c: string | undefined = g();
+export const d: string = g({ a: { b: "" } });
@@ -0,0 +1,12 @@
+// @strict: true
+
+function f(o: { x: number }) {
+    return o?.x;
+}
+
+function g(o: { a: { b: string } }) {
+    return o?.a?.b;
+}
+
+export const a: number = f({ x: 1 });
+export const b: string = g({ a: { b: "" } });
@@ -0,0 +1,8 @@
+function f(a: string): string;
+function f(a: number): number;
+function f(a: any) {
+    return a;
+}
+
+export const a: string = f("a");
+export const b: number = f(1);
@@ -0,0 +1,4 @@
+function f(a: string): number;
+function f(a: any): string {
+    return a;
+}
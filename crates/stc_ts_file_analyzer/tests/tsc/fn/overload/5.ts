@@ -0,0 +1,18 @@
+function f(a: string): string;
+function f(a: number): number;
+function f(a: any): string | number {
+    return a;
+}
+
+function g(a: string): string | number;
+function g(a: any): string {
+    return a;
+}
+
+function h(a: string): void;
+function h(a: any): number {
+    return 1;
+}
+
+export const a: string = f("");
+export const b: number = f(1);
@@ -0,0 +1,3 @@
+function f(): void;
+
+export const a = 1;
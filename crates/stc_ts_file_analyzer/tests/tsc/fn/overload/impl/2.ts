@@ -0,0 +1,8 @@
+export function f(a: string): void;
+export function f(a: number): void;
+export function f(a: any) {}
+
+export class C {
+    constructor();
+    constructor(a?: number) {}
+}
@@ -0,0 +1,3 @@
+export function f(): void;
+export const a = 1;
+export function f() {}
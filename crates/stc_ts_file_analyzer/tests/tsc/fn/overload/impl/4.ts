@@ -0,0 +1,4 @@
+export class C {
+    constructor() {}
+    constructor(a: number) {}
+}
@@ -0,0 +1,3 @@
+export function foo(): void;
+function foo(s: string): void;
+function foo(s?: string) {}
@@ -0,0 +1,3 @@
+declare function bar(): void;
+function bar(s: string): void;
+function bar(s?: string) {}
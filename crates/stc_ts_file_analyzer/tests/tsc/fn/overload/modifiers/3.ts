@@ -0,0 +1,3 @@
+async function baz(): Promise<void>;
+function baz(s: string): Promise<void>;
+async function baz(s?: string) {}
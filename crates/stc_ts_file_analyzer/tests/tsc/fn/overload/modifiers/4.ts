@@ -0,0 +1,6 @@
+export function qux(): void;
+export function qux(s: string): void;
+export function qux(s?: string) {}
+
+declare function quux(): void;
+declare function quux(s: string): void;
@@ -0,0 +1,5 @@
+namespace M {
+    function fn(n: string): void;
+    export function fn(): void;
+    export function fn() {}
+}
@@ -0,0 +1,7 @@
+function f(x: number): Missing;
+function f(x: string): string;
+function f(x: any): any {
+    return x;
+}
+
+export const a: string = f(1);
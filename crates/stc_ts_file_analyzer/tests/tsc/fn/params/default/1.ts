@@ -0,0 +1,9 @@
+export function f(a?: number, b = 1) {}
+f();
+
+export function g(a = 1, b: string) {}
+g(undefined, "");
+g(1);
+
+export function h(a = 1, ...rest: string[]) {}
+h();
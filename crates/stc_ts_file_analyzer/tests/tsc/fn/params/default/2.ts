@@ -0,0 +1 @@
+export function f(a = 1, b?: number, c: string) {}
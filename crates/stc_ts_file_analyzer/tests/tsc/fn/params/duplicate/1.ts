@@ -0,0 +1,3 @@
+export function f(a: number, a: string) {}
+
+export function g({ b }: { b: number }, b: string) {}
@@ -0,0 +1,9 @@
+// @strictNullChecks: true
+// @exactOptionalPropertyTypes: true
+
+// `exactOptionalPropertyTypes` only applies to properties.
+function f(x?: string) {}
+
+f();
+f("a");
+f(undefined);
@@ -0,0 +1,6 @@
+// @strictNullChecks: true
+// @exactOptionalPropertyTypes: true
+
+function f(x: string) {}
+
+f(undefined);
@@ -0,0 +1,6 @@
+function f<T>(x = {} as T) {
+    return x;
+}
+
+const n: number = f(1);
+const s: string = f("a");
@@ -0,0 +1,7 @@
+function f<T>(x: T = 1) {}
+
+function g<T>(x: T[] = []) {
+    return x;
+}
+
+const r: unknown[] = g();
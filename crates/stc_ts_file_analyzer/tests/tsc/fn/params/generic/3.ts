@@ -0,0 +1,14 @@
+// @strict: true
+
+function apply<A extends any[]>(f: (...args: A) => void, args: A): void {
+    f(...args);
+}
+
+apply((a: number, b: string) => {}, [1, "x"]);
+apply((a: number, b: string) => {}, ["x", 1]);
+
+function call<A extends unknown[], R>(f: (...args: A) => R, ...args: A): R {
+    return f(...args);
+}
+
+const r: number = call((a: number, b: string) => a, 1, "x");
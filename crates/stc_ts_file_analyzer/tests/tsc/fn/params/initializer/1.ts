@@ -0,0 +1,3 @@
+export function f(a = b, b: number) {
+    return a;
+}
@@ -0,0 +1,3 @@
+export function f(a = a) {
+    return a;
+}
@@ -0,0 +1,7 @@
+export function f(a: number, b = a) {
+    return b;
+}
+
+export function g(a = () => b, b = 1) {
+    return a;
+}
@@ -0,0 +1,6 @@
+function f(a: typeof missing, b: number) {
+    const x: string = b;
+}
+
+f(1, 2);
+export const y: string = f(1, 2);
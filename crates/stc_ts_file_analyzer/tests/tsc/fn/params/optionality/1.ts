@@ -0,0 +1,12 @@
+// @strictNullChecks: true
+
+export function f1(a: number, b?: number) {}
+export function f2(a?: number, b?: number) {}
+export function f3(a?: number, ...rest: string[]) {}
+export function f4(a?: number, b = 1) {}
+export function f5(a = 1, b: number) {}
+export function f6(a?: number, b: number) {}
+export function f7(a?: number, b = 1, c: number) {}
+export function f8(...rest: string[], a?: number) {}
+export declare function f9(a?: number, [b]?: number[]): void;
+export declare function f10([a]?: number[], b: number): void;
@@ -0,0 +1,13 @@
+// @strict: true
+
+function f(a: number, b?: string) {
+    return a + (b ?? "");
+}
+
+type P = Parameters<typeof f>;
+
+const a: P = [1];
+const b: P = [1, "b"];
+const c: P = ["a"];
+const d: P = [1, 2];
+const e: [number, string?] = [] as unknown as P;
@@ -0,0 +1,14 @@
+class C {
+    constructor(private x: number, readonly y: string, public z = 1) {}
+
+    get() {
+        return this.x;
+    }
+}
+
+const c = new C(1, "");
+export const a: number = c.get();
+export const b: string = c.y;
+export const d: number = c.z;
+c.x;
+c.y = "";
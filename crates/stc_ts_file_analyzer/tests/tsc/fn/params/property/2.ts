@@ -0,0 +1,4 @@
+export class C {
+    constructor(private x: number);
+    constructor(x: any) {}
+}
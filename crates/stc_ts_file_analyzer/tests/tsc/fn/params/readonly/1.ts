@@ -0,0 +1,6 @@
+export function f(a: readonly number[]) {}
+
+declare const arr: number[];
+declare const ro: readonly number[];
+f(arr);
+f(ro);
@@ -0,0 +1,6 @@
+export function f(a: number[]) {}
+
+declare const ro: readonly number[];
+f(ro);
+
+export const m: number[] = ro;
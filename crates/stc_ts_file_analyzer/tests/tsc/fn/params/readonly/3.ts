@@ -0,0 +1,8 @@
+export function f(a: readonly [number, string]) {}
+export function g(a: [number, string]) {}
+
+declare const t: [number, string];
+declare const rt: readonly [number, string];
+f(t);
+f(rt);
+g(rt);
@@ -0,0 +1,6 @@
+// @strictFunctionTypes: true
+
+declare let a: (x: readonly number[]) => void;
+declare let b: (x: number[]) => void;
+b = a;
+a = b;
@@ -0,0 +1 @@
+export function f(...args: number[], last: number) {}
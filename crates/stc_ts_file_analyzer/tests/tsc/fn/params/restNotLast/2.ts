@@ -0,0 +1,3 @@
+export function f(a?: number, ...rest: number[]) {}
+
+export function g(a: number, ...rest: string[]) {}
@@ -0,0 +1,9 @@
+declare function f(...args: [number, ...string[]]): void;
+
+declare const t1: [number, string, string];
+declare const t2: [number];
+
+f(...t1);
+f(...t2);
+f(1, ...["a", "b"]);
+f(1, "a", "b");
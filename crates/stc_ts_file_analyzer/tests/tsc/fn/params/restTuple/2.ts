@@ -0,0 +1,7 @@
+declare function f(...args: [number, ...string[]]): void;
+
+declare const t1: [string, string];
+declare const t2: [number, string, number];
+
+f(...t1);
+f(...t2);
@@ -0,0 +1,8 @@
+export function fib(n: number) {
+    if (n < 2) {
+        return n;
+    }
+    return fib(n - 1) + fib(n - 2);
+}
+
+export const r = fib(10);
@@ -0,0 +1,7 @@
+export function count(n: number): number {
+    if (n <= 0) {
+        return 0;
+    }
+    const prev: string = count(n - 1);
+    return 1;
+}
@@ -0,0 +1,9 @@
+function f(x: string): string;
+function f(x: number): number;
+function f(x: any): any {
+    if (typeof x === "string") {
+        const n: string = f(x.length);
+        return n;
+    }
+    return x;
+}
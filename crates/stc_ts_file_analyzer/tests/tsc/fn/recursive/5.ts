@@ -0,0 +1,7 @@
+function g(n: number): number;
+function g(n: string);
+function g(n: any) {
+    return typeof n === "number" ? g(String(n)) : n;
+}
+
+export const r: number = g(1);
@@ -0,0 +1,28 @@
+// @strict: true
+
+interface Tree {
+    value: number;
+    children: Tree[];
+}
+
+const obj = {
+    a: 1,
+    b: "b",
+};
+
+function get<K extends keyof typeof obj>(key: K): (typeof obj)[K] {
+    return obj[key];
+}
+
+function childrenOf(tree: Tree): Tree["children"] {
+    return tree.children;
+}
+
+function self(): typeof self {
+    return self;
+}
+
+const a: number = get("a");
+const b: number = get("b");
+const c: number = childrenOf({ value: 1, children: [] })[0].value;
+const d: () => unknown = self();
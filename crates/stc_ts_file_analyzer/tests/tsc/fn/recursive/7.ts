@@ -0,0 +1,8 @@
+function countdown(n: number) {
+    if (n > 0) {
+        countdown(n - 1);
+    }
+    return n;
+}
+
+export const r: string = countdown(10);
@@ -0,0 +1,16 @@
+function isEven(n: number) {
+    if (n === 0) {
+        return true;
+    }
+    return !isOdd(n - 1);
+}
+
+function isOdd(n: number) {
+    if (n === 0) {
+        return false;
+    }
+    return !isEven(n - 1);
+}
+
+export const a: string = isEven(10);
+export const b: string = isOdd(10);
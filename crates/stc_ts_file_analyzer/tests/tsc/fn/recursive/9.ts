@@ -0,0 +1,6 @@
+function self() {
+    return self;
+}
+
+export const a: typeof self = self();
+export const b: number = self();
@@ -0,0 +1,3 @@
+function f(): number {}
+
+async function g(): Promise<string> {}
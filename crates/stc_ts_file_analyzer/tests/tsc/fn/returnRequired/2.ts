@@ -0,0 +1,11 @@
+function f(): number {
+    throw new Error();
+}
+
+function g(x: boolean): string {
+    if (x) {
+        throw new Error("x");
+    } else {
+        throw new Error("y");
+    }
+}
@@ -0,0 +1,3 @@
+// @strictNullChecks: true
+
+function f(): undefined {}
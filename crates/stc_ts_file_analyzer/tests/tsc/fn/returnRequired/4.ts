@@ -0,0 +1,5 @@
+function f(): number | void {}
+
+async function g(): Promise<void> {}
+
+function h(): any {}
@@ -0,0 +1,5 @@
+export function f(
+    a: number
+): number {
+    a;
+}
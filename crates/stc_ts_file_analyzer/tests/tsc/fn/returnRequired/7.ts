@@ -0,0 +1,5 @@
+export const g = function (
+    a: number
+): string {
+    a;
+};
@@ -0,0 +1,10 @@
+function f(flag: boolean) {
+    if (flag) {
+        return 1;
+    } else {
+        return "foo";
+    }
+}
+
+export const a: number | string = f(true);
+export const b: number = f(true);
@@ -0,0 +1,13 @@
+function f(n: number) {
+    switch (n) {
+        case 0:
+            return 1;
+        case 1:
+            return "foo";
+        default:
+            return true;
+    }
+}
+
+export const a: number | string | boolean = f(0);
+export const b: number | string = f(0);
@@ -0,0 +1,20 @@
+// @strictNullChecks: true
+
+function f(flag: boolean) {
+    if (flag) {
+        return 1;
+    }
+}
+
+function g(n: number) {
+    switch (n) {
+        case 0:
+            return 1;
+        default:
+            return 2;
+    }
+}
+
+export const a: number | undefined = f(true);
+export const b: number = f(true);
+export const c: number = g(0);
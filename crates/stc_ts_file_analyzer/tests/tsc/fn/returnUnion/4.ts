@@ -0,0 +1,17 @@
+function make(flag: boolean) {
+    if (flag) {
+        return { kind: "a" as const, x: 1 };
+    }
+    return { kind: "b" as const, y: "" };
+}
+
+export function use(flag: boolean) {
+    const r = make(flag);
+    if (r.kind === "a") {
+        const x: number = r.x;
+        const b: boolean = r.x;
+    } else {
+        const y: string = r.y;
+        const b: boolean = r.y;
+    }
+}
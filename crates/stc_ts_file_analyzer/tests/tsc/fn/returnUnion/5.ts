@@ -0,0 +1,9 @@
+function make(flag: boolean) {
+    if (flag) {
+        return { kind: "a" as const, x: 1 };
+    }
+    return { kind: "b" as const, y: "" };
+}
+
+export const u: { kind: "a"; x: number } | { kind: "b"; y: string } = make(true);
+export const a: { kind: "a"; x: number } = make(true);
@@ -0,0 +1,8 @@
+function f(x: string[] | number) {
+    if (typeof x === "number") return x;
+    return x[0];
+}
+
+export const a: number | string = f(1);
+export const b: number = f(1);
+export const c: string[] = f(1);
@@ -0,0 +1,52 @@
+// @strictNullChecks: true
+
+function loop(n: number) {
+    switch (n) {
+        case 0:
+            for (const x of [1, 2]) {
+                if (x) break;
+            }
+            return 1;
+        default:
+            return 2;
+    }
+}
+
+function nestedSwitch(n: number) {
+    switch (n) {
+        case 0:
+            switch (n) {
+                case 0:
+                    break;
+            }
+            return 1;
+        default:
+            return 2;
+    }
+}
+
+function nestedFn(n: number) {
+    switch (n) {
+        case 0:
+            [1].forEach(function () {
+                while (n) break;
+            });
+            return 1;
+        default:
+            return 2;
+    }
+}
+
+function exits(n: number) {
+    switch (n) {
+        case 0:
+            break;
+        default:
+            return 2;
+    }
+}
+
+export const a: number = loop(0);
+export const b: number = nestedSwitch(0);
+export const c: number = nestedFn(0);
+export const d: number = exits(0);
@@ -0,0 +1,28 @@
+// @strictNullChecks: true
+
+function labeled(n: number) {
+    sw: switch (n) {
+        case 0:
+            for (const x of [1, 2]) {
+                if (x) break sw;
+            }
+            return 1;
+        default:
+            return 2;
+    }
+}
+
+function outer(n: number) {
+    sw: switch (n) {
+        case 0:
+            other: for (const x of [1, 2]) {
+                if (x) break other;
+            }
+            return 1;
+        default:
+            return 2;
+    }
+}
+
+export const a: number = labeled(0);
+export const b: number = outer(0);
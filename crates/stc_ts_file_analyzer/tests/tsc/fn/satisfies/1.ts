@@ -0,0 +1,6 @@
+// @noImplicitAny: true
+
+export const f = ((x) => x.length) satisfies (s: string) => number;
+export const g = function (x) {
+    return x.length;
+} satisfies (s: string) => number;
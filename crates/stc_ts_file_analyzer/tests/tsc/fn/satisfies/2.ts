@@ -0,0 +1,4 @@
+export const f = ((x) => {
+    const n: number = x;
+    return 1;
+}) satisfies (s: string) => number;
@@ -0,0 +1,8 @@
+declare function getNums(): number[];
+
+export function f() {
+    return [...getNums()];
+}
+
+export const a: number[] = f();
+export const b: [number] = f();
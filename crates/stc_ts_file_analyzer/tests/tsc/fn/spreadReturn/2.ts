@@ -0,0 +1,8 @@
+declare function getNums(): number[];
+
+export function f() {
+    return [1, ...getNums()];
+}
+
+export const a: number[] = f();
+export const b: [number, ...number[]] = f();
@@ -0,0 +1,9 @@
+declare function getNums(): number[];
+
+export function f() {
+    return [1, ...getNums()] as const;
+}
+
+export const a: readonly [1, ...number[]] = f();
+export const first: 1 = f()[0];
+export const second: string = f()[1];
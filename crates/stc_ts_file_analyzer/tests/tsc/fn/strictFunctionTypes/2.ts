@@ -0,0 +1,14 @@
+// @strictFunctionTypes: false
+
+interface Animal {
+    name: string;
+}
+interface Dog extends Animal {
+    bark(): void;
+}
+
+declare let f1: (x: Animal) => void;
+declare let f2: (x: Dog) => void;
+
+f2 = f1;
+f1 = f2;
@@ -0,0 +1,22 @@
+// @strictFunctionTypes: true
+
+interface Animal {
+    name: string;
+}
+interface Dog extends Animal {
+    bark(): void;
+}
+
+// Parameters of methods are compared bivariantly.
+declare let m1: { m(x: Animal): void };
+declare let m2: { m(x: Dog): void };
+
+m1 = m2;
+m2 = m1;
+
+// Parameters of function-typed properties are not.
+declare let p1: { p: (x: Animal) => void };
+declare let p2: { p: (x: Dog) => void };
+
+p2 = p1;
+p1 = p2;
@@ -0,0 +1,16 @@
+// @strictFunctionTypes: true
+
+interface Animal {
+    name: string;
+}
+interface Dog extends Animal {
+    bark(): void;
+}
+
+declare let g: (x: Dog) => void;
+
+declare let o: { m(x: Animal): void };
+o.m = g;
+
+declare let p: { p: (x: Animal) => void };
+p.p = g;
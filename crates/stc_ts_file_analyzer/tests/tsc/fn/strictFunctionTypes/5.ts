@@ -0,0 +1,26 @@
+// @strictFunctionTypes: true
+
+interface Animal {
+    name: string;
+}
+interface Dog extends Animal {
+    bark(): void;
+}
+
+class MethodAnimal {
+    m(x: Animal) {}
+}
+class MethodDog {
+    m(x: Dog) {}
+}
+
+export const a: MethodAnimal = new MethodDog();
+
+class PropertyAnimal {
+    p = (x: Animal) => {};
+}
+class PropertyDog {
+    p = (x: Dog) => {};
+}
+
+export const b: PropertyAnimal = new PropertyDog();
@@ -0,0 +1,24 @@
+// @strictFunctionTypes: true
+
+class A {
+    m(x: string): string {
+        return x;
+    }
+}
+class B {
+    m(x: string): number {
+        return 1;
+    }
+}
+
+export const a: A = new B();
+
+interface I {
+    m(x: string): string;
+}
+interface J {
+    m(x: string): number;
+}
+declare const j: J;
+
+export const i: I = j;
@@ -0,0 +1,7 @@
+export function getX(this: { x: number }, y: number): number {
+    const s: string = this.x;
+    return this.x + y;
+}
+
+declare const f: (this: void, a: number) => void;
+f(1);
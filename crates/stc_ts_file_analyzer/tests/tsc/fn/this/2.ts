@@ -0,0 +1,7 @@
+export class Base {
+    m(this: Base, a: number) {}
+}
+
+export class Derived extends Base {
+    m(this: Derived, a: number) {}
+}
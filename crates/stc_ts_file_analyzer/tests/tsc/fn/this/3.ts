@@ -0,0 +1,3 @@
+export function f(a: number, this: string) {}
+
+export const g = (this: number) => {};
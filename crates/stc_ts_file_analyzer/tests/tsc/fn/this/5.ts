@@ -0,0 +1,15 @@
+interface Foo {
+    x: number;
+}
+interface Bar {
+    y: string;
+}
+
+declare let unbound: (this: void) => void;
+declare let fooCb: (this: Foo) => void;
+declare let barCb: (this: Bar) => void;
+
+fooCb = unbound;
+barCb = unbound;
+barCb = fooCb;
+unbound = fooCb;
@@ -0,0 +1,7 @@
+class C {
+    n = 1;
+}
+
+export function optional(this?: C): number {
+    return 1;
+}
@@ -0,0 +1,21 @@
+class Builder {
+    name = "";
+
+    setName(name: string): this {
+        this.name = name;
+        return this;
+    }
+}
+
+class SizedBuilder extends Builder {
+    size = 0;
+
+    setSize(size: number): this {
+        this.size = size;
+        return this;
+    }
+}
+
+export const a: SizedBuilder = new SizedBuilder().setName("a").setSize(1);
+export const b: SizedBuilder = new SizedBuilder().setSize(1).setName("b");
+export const c: Builder = new Builder().setName("c");
@@ -0,0 +1,14 @@
+class Builder {
+    setName(name: string): this {
+        return this;
+    }
+}
+
+class SizedBuilder extends Builder {
+    setSize(size: number): this {
+        return this;
+    }
+}
+
+export const a: SizedBuilder = new Builder().setName("a");
+new Builder().setName("b").setSize(1);
@@ -0,0 +1,22 @@
+interface Data {
+    count: number;
+}
+interface Methods {
+    inc(): void;
+    reset(): void;
+}
+
+declare function defineComponent(options: { data: Data; methods: Methods } & ThisType<Data & Methods>): void;
+
+defineComponent({
+    data: { count: 0 },
+    methods: {
+        inc() {
+            this.count++;
+        },
+        reset() {
+            this.count = 0;
+            this.inc();
+        },
+    },
+});
@@ -0,0 +1,15 @@
+interface Data {
+    count: number;
+}
+
+declare function defineComponent(options: { data: Data; methods: { [name: string]: () => void } } & ThisType<Data>): void;
+
+defineComponent({
+    data: { count: 0 },
+    methods: {
+        inc() {
+            this.count = "a";
+            this.missing();
+        },
+    },
+});
@@ -0,0 +1,18 @@
+// @strict: true
+
+const counter = {
+    count: 0,
+    label: "counter",
+    describe() {
+        const n: number = this.count;
+        const s: string = this.label;
+        return n + s;
+    },
+    wrong() {
+        const s: string = this.count;
+        return s;
+    },
+    usesMethod() {
+        return this.describe().length;
+    },
+};
@@ -0,0 +1,5 @@
+// @noImplicitAny: true
+
+export function f() {
+    return [null];
+}
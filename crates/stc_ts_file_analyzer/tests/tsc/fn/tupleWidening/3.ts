@@ -0,0 +1,5 @@
+// @noImplicitAny: true
+
+export function f(): null[] {
+    return [null];
+}
@@ -0,0 +1,10 @@
+export function get<T, K extends keyof T>(obj: T, key: K): T[K] {
+    return obj[key];
+}
+
+export function pick<K extends keyof T, T>(obj: T, key: K): T[K] {
+    return obj[key];
+}
+
+get({ a: 1 }, "a");
+get({ a: 1 }, "b");
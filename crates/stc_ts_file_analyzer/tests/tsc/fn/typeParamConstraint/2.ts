@@ -0,0 +1,3 @@
+export function f<T extends T>(x: T) {}
+
+export function g<T extends U, U extends T>(x: T, y: U) {}
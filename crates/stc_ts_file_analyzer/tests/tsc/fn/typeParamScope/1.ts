@@ -0,0 +1,17 @@
+function cast<T>(x: unknown): T {
+    return x as T;
+}
+
+function nested<T>(x: unknown) {
+    const inner = () => x as T;
+    return inner();
+}
+
+const n: number = cast<number>("a");
+const s: string = nested<string>(1);
+
+const outside = 1 as unknown as T;
+
+function other() {
+    return "a" as unknown as T;
+}
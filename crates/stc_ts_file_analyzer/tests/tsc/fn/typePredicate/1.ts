@@ -0,0 +1,9 @@
+function isString(x: unknown): x is string {
+    return typeof x === "string";
+}
+
+declare const v: string | number;
+
+if (isString(v)) {
+    const s: string = v;
+}
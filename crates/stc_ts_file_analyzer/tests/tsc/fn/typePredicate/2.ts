@@ -0,0 +1,7 @@
+export function f(x: unknown): y is string {
+    return true;
+}
+
+export function g(x: number): x is string {
+    return true;
+}
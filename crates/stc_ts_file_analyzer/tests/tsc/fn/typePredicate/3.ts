@@ -0,0 +1,25 @@
+class Animal {
+    isDog(): this is Dog {
+        return this instanceof Dog;
+    }
+
+    greet() {
+        if (this.isDog()) {
+            this.bark();
+        }
+    }
+}
+
+class Dog extends Animal {
+    bark() {}
+}
+
+function isDog(this: unknown, x: Animal): x is Dog {
+    return x instanceof Dog;
+}
+
+declare const a: Animal;
+
+if (isDog(a)) {
+    a.bark();
+}
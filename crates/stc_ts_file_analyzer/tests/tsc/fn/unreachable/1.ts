@@ -0,0 +1,7 @@
+// @alwaysStrict: true
+// @allowUnreachableCode: false
+
+function foo(): number {
+    return 1;
+    console.log("unreachable");
+}
@@ -0,0 +1,7 @@
+// @alwaysStrict: true
+// @allowUnreachableCode: false
+
+function fail(message: string) {
+    throw new Error(message);
+    console.log(message);
+}
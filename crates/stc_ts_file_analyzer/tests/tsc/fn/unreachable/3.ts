@@ -0,0 +1,14 @@
+// @alwaysStrict: true
+// @allowUnreachableCode: false
+
+function cleanup() {}
+
+function foo(): number {
+    try {
+        return 1;
+    } finally {
+        cleanup();
+    }
+
+    function bar() {}
+}
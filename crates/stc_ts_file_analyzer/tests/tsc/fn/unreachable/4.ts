@@ -0,0 +1,29 @@
+// @alwaysStrict: true
+// @allowUnreachableCode: false
+
+export function foo(n: number) {
+    if (n) {
+        return 1;
+        console.log("if");
+    }
+
+    for (const x of [1, 2]) {
+        throw new Error();
+        console.log(x);
+    }
+
+    {
+        return 2;
+        console.log("block");
+    }
+}
+
+export function bar(n: number) {
+    switch (n) {
+        case 0:
+            return 1;
+            console.log("case");
+        default:
+            return 2;
+    }
+}
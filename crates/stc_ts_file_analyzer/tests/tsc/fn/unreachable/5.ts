@@ -0,0 +1,14 @@
+// @alwaysStrict: true
+// @allowUnreachableCode: false
+
+declare function foo(): void;
+
+export function f(n: number) {
+    switch (n) {
+        case 0:
+            return 1;
+        default:
+            return 2;
+    }
+    foo();
+}
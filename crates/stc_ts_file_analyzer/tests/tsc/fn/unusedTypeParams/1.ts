@@ -0,0 +1,9 @@
+// @noUnusedParameters: true
+
+export function f<T>() {}
+
+export function g<T, U>() {}
+
+export function h<T, U>(x: T) {
+    return x;
+}
@@ -0,0 +1,18 @@
+// @noUnusedParameters: true
+
+export function f<T>(x: T) {
+    return x;
+}
+
+export function g<T>(): T[] {
+    return [];
+}
+
+export function h<T>() {
+    const x: T[] = [];
+    return x.length;
+}
+
+export function i<T, U extends T>(u: U) {
+    return u;
+}
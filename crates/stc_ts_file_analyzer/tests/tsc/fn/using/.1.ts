@@ -0,0 +1,10 @@
+// @target: esnext
+// @lib: esnext
+
+declare function getResource(): { [Symbol.dispose](): void };
+declare function getValue(): { value: number };
+
+export function f() {
+    using r = getResource();
+    using v = getValue();
+}
@@ -0,0 +1,6 @@
+[1, 2, 3].forEach((x) => x * 2);
+
+export const f: () => void = () => 1;
+
+type V = void;
+export const g: () => V = () => 1;
@@ -0,0 +1,7 @@
+function f(): void {
+    return 1;
+}
+
+export const g = (): void => 1;
+
+export const h: void = 1;
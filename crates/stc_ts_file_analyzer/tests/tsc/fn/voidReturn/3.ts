@@ -0,0 +1,16 @@
+// @strictNullChecks: true
+
+function none() {}
+
+function bare() {
+    return;
+}
+
+function explicit() {
+    return undefined;
+}
+
+const a: undefined = none();
+const b: undefined = bare();
+const c: undefined = explicit();
+const d: string = explicit();
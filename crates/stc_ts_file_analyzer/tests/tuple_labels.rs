@@ -0,0 +1,50 @@
+#![feature(box_syntax)]
+
+use stc_ts_ast_rnode::{RPat, RTsFnParam, RTsType};
+use stc_ts_file_analyzer::validator::ValidateWith;
+use stc_ts_types::{Function, Type};
+
+use self::common::{find_fn, run};
+
+mod common;
+
+fn type_of_fn(src: &str) -> Function {
+    run(src, Default::default(), |analyzer, module| {
+        find_fn(module, "f")
+            .validate_with_args(analyzer, (None, None))
+            .expect("function should be valid")
+    })
+    .0
+}
+
+fn label_name(label: Option<&RPat>) -> Option<&str> {
+    match label? {
+        RPat::Ident(i) => Some(&*i.id.sym),
+        _ => None,
+    }
+}
+
+#[test]
+fn labels_of_rest_param() {
+    let f = type_of_fn("function f(...args: [first: number, second?: string]) {}");
+
+    let param = &f.params[0];
+    let tuple = match param.ty.normalize() {
+        Type::Tuple(tuple) => tuple,
+        ty => panic!("rest parameter should be a tuple, got {:?}", ty),
+    };
+    let labels = tuple.elems.iter().map(|e| label_name(e.label.as_ref())).collect::<Vec<_>>();
+    assert_eq!(labels, vec![Some("first"), Some("second")]);
+
+    // Labels are emitted in declaration files.
+    let type_ann = match RTsFnParam::from(param.clone()) {
+        RTsFnParam::Rest(rest) => rest.type_ann.expect("rest parameter should have a type annotation").type_ann,
+        p => panic!("expected a rest parameter, got {:?}", p),
+    };
+    let tuple = match *type_ann {
+        RTsType::TsTupleType(tuple) => tuple,
+        ty => panic!("expected a tuple type, got {:?}", ty),
+    };
+    let labels = tuple.elem_types.iter().map(|e| label_name(e.label.as_ref())).collect::<Vec<_>>();
+    assert_eq!(labels, vec![Some("first"), Some("second")]);
+}
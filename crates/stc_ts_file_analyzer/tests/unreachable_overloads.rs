@@ -0,0 +1,83 @@
+#![feature(box_syntax)]
+
+use stc_ts_env::Rule;
+use stc_ts_errors::ErrorKind;
+use stc_ts_file_analyzer::validator::ValidateWith;
+
+use self::common::run;
+
+mod common;
+
+/// Validates `src` and returns the number of reported unreachable overloads.
+fn unreachable_overloads(src: &str, report_unreachable_overloads: bool) -> usize {
+    let rule = Rule {
+        report_unreachable_overloads,
+        ..Default::default()
+    };
+
+    let (_, info) = run(src, rule, |analyzer, module| {
+        module.validate_with(analyzer).expect("module should be valid");
+    });
+
+    info.errors
+        .into_iter()
+        .filter(|err| matches!(**err, ErrorKind::UnreachableOverload { .. }))
+        .count()
+}
+
+const BROAD_THEN_NARROW: &str = "
+export function f(x: string | number): string;
+export function f(x: string): string;
+export function f(x: any): any {
+    return x;
+}
+";
+
+#[test]
+fn broad_then_narrow() {
+    assert_eq!(unreachable_overloads(BROAD_THEN_NARROW, true), 1);
+}
+
+#[test]
+fn narrow_then_broad() {
+    let src = "
+export function f(x: string): string;
+export function f(x: string | number): string;
+export function f(x: any): any {
+    return x;
+}
+";
+
+    assert_eq!(unreachable_overloads(src, true), 0);
+}
+
+#[test]
+fn fewer_required_params_first() {
+    let src = "
+export function f(x: string, y?: number): string;
+export function f(x: string, y: number): string;
+export function f(x: any, y?: any): any {
+    return x;
+}
+";
+
+    assert_eq!(unreachable_overloads(src, true), 1);
+}
+
+#[test]
+fn more_params_later() {
+    let src = "
+export function f(x: string): string;
+export function f(x: string, y: number): string;
+export function f(x: any, y?: any): any {
+    return x;
+}
+";
+
+    assert_eq!(unreachable_overloads(src, true), 0);
+}
+
+#[test]
+fn disabled_by_default() {
+    assert_eq!(unreachable_overloads(BROAD_THEN_NARROW, false), 0);
+}
@@ -0,0 +1,54 @@
+#![feature(box_syntax)]
+
+use rnode::VisitWith;
+use stc_ts_errors::ErrorKind;
+
+use self::common::run;
+
+mod common;
+
+/// Returns the offset of the related span of each `TS2345` error reported for
+/// `src`.
+fn related_spans_of_wrong_args(src: &str) -> Vec<usize> {
+    let (start_pos, info) = run(src, Default::default(), |analyzer, module| {
+        module.visit_with(analyzer);
+
+        module.span.lo
+    });
+
+    ErrorKind::flatten(info.errors.into_iter().collect())
+        .iter()
+        .filter(|err| matches!(&**err, ErrorKind::WrongArgType { .. }))
+        .map(|err| {
+            let (span, _) = err.related_info().expect("TS2345 should have a related span");
+            (span.lo - start_pos).0 as usize
+        })
+        .collect()
+}
+
+const SRC_PARAM: &str = "function f(a: string, b: number) {}
+f('', '');";
+
+#[test]
+fn points_at_declared_param() {
+    assert_eq!(related_spans_of_wrong_args(SRC_PARAM), vec![SRC_PARAM.find("b: number").unwrap()]);
+}
+
+const SRC_REST: &str = "function f(a: string, ...rest: number[]) {}
+f('', 1, '');";
+
+#[test]
+fn points_at_rest_param() {
+    assert_eq!(related_spans_of_wrong_args(SRC_REST), vec![SRC_REST.find("...rest").unwrap()]);
+}
+
+const SRC_FN_TYPE: &str = "declare const f: (value: number) => void;
+f('');";
+
+#[test]
+fn points_at_param_of_fn_type() {
+    assert_eq!(
+        related_spans_of_wrong_args(SRC_FN_TYPE),
+        vec![SRC_FN_TYPE.find("value: number").unwrap()]
+    );
+}
@@ -1,5 +1,5 @@
 use rnode::{Visit, VisitWith};
-use stc_ts_ast_rnode::{RDecl, RFnDecl, RIdent, RStmt, RTsModuleDecl, RTsNamespaceDecl};
+use stc_ts_ast_rnode::{RDecl, RExportDecl, RFnDecl, RIdent, RModuleDecl, RStmt, RTsModuleDecl, RTsNamespaceDecl};
 use stc_ts_errors::ErrorKind;
 use stc_ts_storage::Storage;
 
@@ -38,6 +38,26 @@ impl Visit<RStmt> for AmbientFunctionHandler<'_, '_> {
     }
 }
 
+/// Handles
+///
+/// ```ts
+/// export function foo(): void;
+/// export const a = 1;
+/// export function foo() {}
+/// ```
+impl Visit<RModuleDecl> for AmbientFunctionHandler<'_, '_> {
+    fn visit(&mut self, node: &RModuleDecl) {
+        node.visit_children_with(self);
+
+        match node {
+            RModuleDecl::ExportDecl(RExportDecl { decl: RDecl::Fn(..), .. }) => {}
+            _ => {
+                self.handle_missing_impl();
+            }
+        }
+    }
+}
+
 impl Visit<RFnDecl> for AmbientFunctionHandler<'_, '_> {
     fn visit(&mut self, node: &RFnDecl) {
         if node.declare {
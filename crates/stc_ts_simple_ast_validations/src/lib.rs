@@ -9,4 +9,5 @@
 
 pub mod ambient_fn;
 pub mod constructor;
+pub mod overload_modifiers;
 pub mod yield_check;
@@ -0,0 +1,114 @@
+use rnode::{Visit, VisitWith};
+use stc_ts_ast_rnode::{RDecl, RFnDecl, RModule, RModuleDecl, RModuleItem, RStmt, RTsModuleBlock, RTsModuleDecl, RTsNamespaceDecl};
+use stc_ts_errors::ErrorKind;
+use stc_ts_storage::Storage;
+
+/// Reports overloads which do not agree on `export` or `declare`.
+///
+/// ```ts
+/// declare function foo(): void; // TS2384
+/// export function foo(s: string): void; // TS2383
+/// function foo(s?: string) {}
+/// ```
+///
+/// Like tsc, modifiers of each signature are compared with the ones of the
+/// implementation, or with the first signature if there's no implementation.
+pub struct OverloadModifierHandler<'a, 'b> {
+    pub in_declare: bool,
+    pub errors: &'a mut Storage<'b>,
+}
+
+struct Signature<'a> {
+    f: &'a RFnDecl,
+    is_exported: bool,
+}
+
+impl OverloadModifierHandler<'_, '_> {
+    pub fn check(&mut self, items: &[RModuleItem]) {
+        let mut group: Vec<Signature> = vec![];
+
+        for item in items {
+            let sig = match item {
+                RModuleItem::Stmt(RStmt::Decl(RDecl::Fn(f))) => Some(Signature { f, is_exported: false }),
+                RModuleItem::ModuleDecl(RModuleDecl::ExportDecl(export)) => match &export.decl {
+                    RDecl::Fn(f) => Some(Signature { f, is_exported: true }),
+                    _ => None,
+                },
+                _ => None,
+            };
+
+            match sig {
+                Some(sig) => {
+                    if group.last().map_or(false, |last| last.f.ident.sym != sig.f.ident.sym) {
+                        self.check_group(&group);
+                        group.clear();
+                    }
+                    group.push(sig);
+                }
+                None => {
+                    self.check_group(&group);
+                    group.clear();
+                }
+            }
+        }
+
+        self.check_group(&group);
+
+        items.visit_with(self);
+    }
+
+    fn check_group(&mut self, group: &[Signature]) {
+        if group.len() < 2 {
+            return;
+        }
+
+        let canonical = group.iter().find(|sig| sig.f.function.body.is_some()).unwrap_or(&group[0]);
+        let canonical_is_ambient = self.is_ambient(canonical.f);
+
+        for sig in group {
+            if sig.is_exported != canonical.is_exported {
+                self.errors
+                    .report(ErrorKind::ExportedAndNonExportedOverloadsMixed { span: sig.f.ident.span }.into());
+            }
+
+            if self.is_ambient(sig.f) != canonical_is_ambient {
+                self.errors
+                    .report(ErrorKind::AmbientAndNonAmbientOverloadsMixed { span: sig.f.ident.span }.into());
+            }
+        }
+    }
+
+    fn is_ambient(&self, f: &RFnDecl) -> bool {
+        self.in_declare || f.declare
+    }
+}
+
+impl Visit<RModule> for OverloadModifierHandler<'_, '_> {
+    fn visit(&mut self, m: &RModule) {
+        self.check(&m.body);
+    }
+}
+
+impl Visit<RTsModuleBlock> for OverloadModifierHandler<'_, '_> {
+    fn visit(&mut self, block: &RTsModuleBlock) {
+        self.check(&block.body);
+    }
+}
+
+impl Visit<RTsNamespaceDecl> for OverloadModifierHandler<'_, '_> {
+    fn visit(&mut self, decl: &RTsNamespaceDecl) {
+        let old = self.in_declare;
+        self.in_declare |= decl.declare;
+        decl.visit_children_with(self);
+        self.in_declare = old;
+    }
+}
+
+impl Visit<RTsModuleDecl> for OverloadModifierHandler<'_, '_> {
+    fn visit(&mut self, decl: &RTsModuleDecl) {
+        let old = self.in_declare;
+        self.in_declare |= decl.declare;
+        decl.visit_children_with(self);
+        self.in_declare = old;
+    }
+}
@@ -3,6 +3,7 @@
 //! All metadata structs should **derive** [Default].
 //! It means, all field should be `false` by default.
 
+use bitflags::bitflags;
 use rnode::{FoldWith, VisitMutWith, VisitWith};
 use serde::{Deserialize, Serialize};
 use stc_visit::Visitable;
@@ -100,11 +101,44 @@ pub struct CommonTypeMetadata {
     /// generalized.
     pub prevent_generalization: bool,
 
+    pub flags: TypeFlags,
+
     pub destructure_key: DestructureId,
 }
 
 impl_basic_traits!(CommonTypeMetadata);
 
+bitflags! {
+    /// Marks stored in [CommonTypeMetadata].
+    ///
+    /// Some of them are meaningful only for specific kinds of types, but
+    /// storing them here keeps the metadata of those types small.
+    #[derive(Default, Serialize, Deserialize)]
+    pub struct TypeFlags: u8 {
+        /// Applied to the type argument of `NoInfer<T>`.
+        ///
+        /// Type parameters are not inferred from types with this mark.
+        const NO_INFER = 1 << 0;
+
+        /// Applied to [crate::Function]s created from a method, like
+        /// `foo() {}` or `foo(): void`, instead of a property with a function
+        /// type.
+        ///
+        /// Parameters of methods are compared bivariantly.
+        const METHOD = 1 << 1;
+
+        /// Applied to [crate::Function]s created from a declaration with a
+        /// `@deprecated` JSDoc tag.
+        const DEPRECATED = 1 << 2;
+
+        /// Applied to [crate::TypeParam]s declared with the `const` modifier,
+        /// like `<const T>`.
+        ///
+        /// Types inferred for them are not generalized.
+        const CONST = 1 << 3;
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct UnionMetadata {
     pub common: CommonTypeMetadata,
@@ -245,6 +279,25 @@ pub struct FunctionMetadata {
 
 impl_traits!(FunctionMetadata);
 
+impl FunctionMetadata {
+    /// Metadata of a function type created from a method.
+    pub fn method() -> Self {
+        let mut metadata = Self::default();
+        metadata.common.flags.insert(TypeFlags::METHOD);
+        metadata
+    }
+
+    /// See [TypeFlags::METHOD].
+    pub fn is_method(&self) -> bool {
+        self.common.flags.contains(TypeFlags::METHOD)
+    }
+
+    /// See [TypeFlags::DEPRECATED].
+    pub fn is_deprecated(&self) -> bool {
+        self.common.flags.contains(TypeFlags::DEPRECATED)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConstructorMetadata {
     pub common: CommonTypeMetadata,
@@ -287,6 +340,13 @@ pub struct TypeParamMetadata {
 
 impl_traits!(TypeParamMetadata);
 
+impl TypeParamMetadata {
+    /// See [TypeFlags::CONST].
+    pub fn is_const(&self) -> bool {
+        self.common.flags.contains(TypeFlags::CONST)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EnumVariantMetadata {
     pub common: CommonTypeMetadata,
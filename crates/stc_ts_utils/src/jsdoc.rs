@@ -0,0 +1,376 @@
+use swc_common::{
+    comments::{CommentKind, Comments},
+    BytePos, Span,
+};
+
+/// Type expression of a `@returns` (or `@return`) tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JsDocReturnType {
+    /// `@returns {number}`
+    Type {
+        /// Text between the braces.
+        text: String,
+        /// Position of the first byte of `text`.
+        pos: BytePos,
+    },
+    /// The tag has a type expression, but it's not closed or empty.
+    Malformed { span: Span },
+}
+
+/// Finds the type of `@returns` tag from the JSDoc comment attached to `pos`.
+///
+/// Returns [None] if there's no tag or the tag does not have a type
+/// expression, like `@returns The result`.
+pub fn find_return_type_in_jsdoc<C>(comments: C, pos: BytePos) -> Option<JsDocReturnType>
+where
+    C: Comments,
+{
+    let mut found = None;
+
+    comments.with_leading(pos, |comments| {
+        // The closest JSDoc comment wins.
+        for c in comments.iter().rev() {
+            if c.kind != CommentKind::Block || !c.text.starts_with('*') {
+                continue;
+            }
+
+            // `/*` is not included in the text.
+            let base = c.span.lo + BytePos(2);
+
+            if let Some(ty) = find_return_type_in_text(without_overloads(&c.text), base, c.span) {
+                found = Some(ty);
+                return;
+            }
+        }
+    });
+
+    found
+}
+
+/// A `@param` tag with a type expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsDocParam {
+    /// Name of the parameter.
+    pub name: String,
+    /// Span of `name`.
+    pub name_span: Span,
+    /// Text between the braces.
+    pub text: String,
+    /// Position of the first byte of `text`.
+    pub pos: BytePos,
+    /// `true` if the name is wrapped in brackets, like `@param {number} [x]`.
+    pub optional: bool,
+}
+
+/// Finds `@param` tags with a type expression from the JSDoc comment
+/// attached to `pos`, in the order of appearance.
+///
+/// Tags without a type expression, malformed tags and tags documenting a
+/// property of a parameter, like `@param {string} opts.name`, are ignored.
+pub fn find_param_types_in_jsdoc<C>(comments: C, pos: BytePos) -> Vec<JsDocParam>
+where
+    C: Comments,
+{
+    let mut found = vec![];
+
+    comments.with_leading(pos, |comments| {
+        // The closest JSDoc comment wins.
+        for c in comments.iter().rev() {
+            if c.kind != CommentKind::Block || !c.text.starts_with('*') {
+                continue;
+            }
+
+            // `/*` is not included in the text.
+            let base = c.span.lo + BytePos(2);
+
+            found = find_param_types_in_text(without_overloads(&c.text), base, c.span);
+            if !found.is_empty() {
+                return;
+            }
+        }
+    });
+
+    found
+}
+
+/// An overload signature declared with an `@overload` tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsDocOverload {
+    /// Span of the tag.
+    pub span: Span,
+    /// `@param` tags following the tag.
+    pub params: Vec<JsDocParam>,
+    /// `@returns` tag following the tag.
+    pub return_type: Option<JsDocReturnType>,
+}
+
+/// Finds `@overload` tags from JSDoc comments attached to `pos`, in the order
+/// of appearance.
+///
+/// Tags following an `@overload` tag, up to the next `@overload` tag or the
+/// end of the comment, belong to the overload.
+pub fn find_overloads_in_jsdoc<C>(comments: C, pos: BytePos) -> Vec<JsDocOverload>
+where
+    C: Comments,
+{
+    let mut found = vec![];
+
+    comments.with_leading(pos, |comments| {
+        for c in comments {
+            if c.kind != CommentKind::Block || !c.text.starts_with('*') {
+                continue;
+            }
+
+            // `/*` is not included in the text.
+            let base = c.span.lo + BytePos(2);
+
+            let mut offset = match find_overload_tag(&c.text, 0) {
+                Some(v) => v,
+                None => continue,
+            };
+
+            loop {
+                let start = offset + "@overload".len();
+                let next = find_overload_tag(&c.text, start);
+                let text = &c.text[start..next.unwrap_or(c.text.len())];
+                let text_base = base + BytePos(start as u32);
+
+                found.push(JsDocOverload {
+                    span: Span::new(base + BytePos(offset as u32), text_base, c.span.ctxt),
+                    params: find_param_types_in_text(text, text_base, c.span),
+                    return_type: find_return_type_in_text(text, text_base, c.span),
+                });
+
+                match next {
+                    Some(next) => offset = next,
+                    None => break,
+                }
+            }
+        }
+    });
+
+    found
+}
+
+/// Returns the position of the first `@overload` tag in `text[offset..]`.
+fn find_overload_tag(text: &str, mut offset: usize) -> Option<usize> {
+    while let Some(idx) = text[offset..].find("@overload") {
+        let tag_start = offset + idx;
+        offset = tag_start + "@overload".len();
+
+        // `@overloads` is not the tag we are looking for.
+        if !text[offset..].starts_with(|c: char| !c.is_whitespace()) {
+            return Some(tag_start);
+        }
+    }
+
+    None
+}
+
+/// Returns the text before the first `@overload` tag.
+fn without_overloads(text: &str) -> &str {
+    match find_overload_tag(text, 0) {
+        Some(idx) => &text[..idx],
+        None => text,
+    }
+}
+
+/// A `@deprecated` tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsDocDeprecated {
+    /// Text following the tag, up to the next tag. Lines are joined with a
+    /// space, and it's empty if the tag does not have a message.
+    pub message: String,
+    /// Span of the tag and the message.
+    pub span: Span,
+}
+
+/// Finds the `@deprecated` tag from the JSDoc comment attached to `pos`.
+pub fn find_deprecated_in_jsdoc<C>(comments: C, pos: BytePos) -> Option<JsDocDeprecated>
+where
+    C: Comments,
+{
+    let mut found = None;
+
+    comments.with_leading(pos, |comments| {
+        // The closest JSDoc comment wins.
+        for c in comments.iter().rev() {
+            if c.kind != CommentKind::Block || !c.text.starts_with('*') {
+                continue;
+            }
+
+            // `/*` is not included in the text.
+            let base = c.span.lo + BytePos(2);
+
+            if let Some(tag) = find_deprecated_in_text(&c.text, base, c.span) {
+                found = Some(tag);
+                return;
+            }
+        }
+    });
+
+    found
+}
+
+fn find_deprecated_in_text(text: &str, base: BytePos, comment_span: Span) -> Option<JsDocDeprecated> {
+    let mut offset = 0;
+
+    while let Some(idx) = text[offset..].find("@deprecated") {
+        let tag_start = offset + idx;
+        offset = tag_start + "@deprecated".len();
+
+        // `@deprecatedSince` is not the tag we are looking for.
+        if text[offset..].starts_with(|c: char| !c.is_whitespace()) {
+            continue;
+        }
+
+        let mut message = vec![];
+        let mut end = offset;
+        let mut line_start = offset;
+
+        for (i, line) in text[offset..].split_inclusive('\n').enumerate() {
+            let content = if i == 0 { line } else { line.trim_start().trim_start_matches('*') };
+            let content = content.trim();
+
+            // The message ends at the next tag.
+            if i != 0 && content.starts_with('@') {
+                break;
+            }
+
+            if !content.is_empty() {
+                message.push(content);
+                end = line_start + line.trim_end().len();
+            }
+
+            line_start += line.len();
+        }
+
+        return Some(JsDocDeprecated {
+            message: message.join(" "),
+            span: Span::new(base + BytePos(tag_start as u32), base + BytePos(end as u32), comment_span.ctxt),
+        });
+    }
+
+    None
+}
+
+fn find_param_types_in_text(text: &str, base: BytePos, comment_span: Span) -> Vec<JsDocParam> {
+    let mut params = vec![];
+    let mut offset = 0;
+
+    while let Some(idx) = text[offset..].find("@param") {
+        offset += idx + "@param".len();
+
+        // `@parameter` is not the tag we are looking for.
+        if !text[offset..].starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        let open = text.len() - text[offset..].trim_start().len();
+        if !text[open..].starts_with('{') {
+            continue;
+        }
+        let (ty_start, ty_end, close) = match find_type_expr(text, open) {
+            Some(v) => v,
+            None => continue,
+        };
+        offset = close + 1;
+
+        let rest = text[offset..].trim_start();
+        let name_start = text.len() - rest.len();
+        let (name_start, name_end, optional) = if let Some(rest) = rest.strip_prefix('[') {
+            let end = match rest.find(|c: char| c == ']' || c == '=') {
+                Some(end) => end,
+                None => continue,
+            };
+            let name = &rest[..end];
+            let start = name_start + 1 + (name.len() - name.trim_start().len());
+            (start, start + name.trim().len(), true)
+        } else {
+            let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+            (name_start, name_start + end, false)
+        };
+
+        let name = &text[name_start..name_end];
+        if name.is_empty() || name.contains('.') {
+            continue;
+        }
+
+        params.push(JsDocParam {
+            name: name.to_string(),
+            name_span: Span::new(
+                base + BytePos(name_start as u32),
+                base + BytePos(name_end as u32),
+                comment_span.ctxt,
+            ),
+            text: text[ty_start..ty_end].to_string(),
+            pos: base + BytePos(ty_start as u32),
+            optional,
+        });
+    }
+
+    params
+}
+
+fn find_return_type_in_text(text: &str, base: BytePos, comment_span: Span) -> Option<JsDocReturnType> {
+    let mut offset = 0;
+
+    while let Some(idx) = text[offset..].find("@return") {
+        let tag_start = offset + idx;
+        let mut rest = &text[tag_start + "@return".len()..];
+        rest = rest.strip_prefix('s').unwrap_or(rest);
+        offset = tag_start + "@return".len();
+
+        // `@returnValue` is not the tag we are looking for.
+        if rest.starts_with(|c: char| !c.is_whitespace() && c != '{') {
+            continue;
+        }
+
+        let rest = rest.trim_start();
+        if !rest.starts_with('{') {
+            return None;
+        }
+
+        let open = text.len() - rest.len();
+        if let Some((start, end, _)) = find_type_expr(text, open) {
+            return Some(JsDocReturnType::Type {
+                text: text[start..end].to_string(),
+                pos: base + BytePos(start as u32),
+            });
+        }
+
+        return Some(JsDocReturnType::Malformed {
+            span: Span::new(base + BytePos(tag_start as u32), comment_span.hi, comment_span.ctxt),
+        });
+    }
+
+    None
+}
+
+/// Finds the type expression starting with the brace at `open`.
+///
+/// Returns the range of the trimmed type and the position of the closing
+/// brace, or [None] if the braces are not closed or the type is empty.
+fn find_type_expr(text: &str, open: usize) -> Option<(usize, usize, usize)> {
+    let mut depth = 0;
+    for (i, c) in text[open..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    let inner = &text[open + 1..open + i];
+                    if inner.trim().is_empty() {
+                        return None;
+                    }
+
+                    let start = open + 1 + (inner.len() - inner.trim_start().len());
+                    return Some((start, start + inner.trim().len(), open + i));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
@@ -15,6 +15,7 @@ pub use self::{comments::StcComments, map_with_mut::MapWithMut};
 
 mod comments;
 pub mod imports;
+pub mod jsdoc;
 mod map_with_mut;
 
 pub trait AsModuleDecl {